@@ -0,0 +1,71 @@
+use std::fmt::Write;
+use std::path::Path;
+
+// Generates a #[test] per example listed in the `data/2022/dayN.fixtures.txt`
+// manifests, grouped in a module per day. tests/fixtures.rs includes them
+fn main() {
+    let data_dir = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("../data/2022");
+    println!("cargo:rerun-if-changed={}", data_dir.display());
+
+    let mut manifests: Vec<(u32, String)> = std::fs::read_dir(&data_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let day = name.strip_prefix("day")?.strip_suffix(".fixtures.txt")?;
+            Some((day.parse().ok()?, name))
+        })
+        .collect();
+    manifests.sort();
+
+    let mut tests = String::new();
+    for (day, manifest) in manifests {
+        let content = std::fs::read_to_string(data_dir.join(&manifest)).unwrap();
+        writeln!(tests, "mod day{} {{\n    use super::check;", day).unwrap();
+        for file in fixture_files(&content) {
+            writeln!(
+                tests,
+                "\n    #[test]\n    fn {}() {{\n        check({}, {:?});\n    }}",
+                test_name(day, &file),
+                day,
+                file
+            )
+            .unwrap();
+        }
+        writeln!(tests, "}}\n").unwrap();
+    }
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    std::fs::write(Path::new(&out_dir).join("fixture_tests.rs"), tests).unwrap();
+}
+
+// The `[<file>]` headers of a manifest. Only headers are needed here, the
+// answers are read by Fixture::from_manifest when the test runs
+fn fixture_files(manifest: &str) -> Vec<String> {
+    let mut files = Vec::new();
+    let mut in_block = false;
+    for line in manifest.lines().map(|l| l.trim()) {
+        if in_block {
+            in_block = line != "\"\"\"";
+        } else if line.ends_with("\"\"\"") {
+            in_block = true;
+        } else if let Some(file) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            files.push(file.trim().to_string());
+        }
+    }
+    files
+}
+
+// `day9.test2.txt` becomes `test2`
+fn test_name(day: u32, file: &str) -> String {
+    let name = file.strip_prefix(&format!("day{}.", day)).unwrap_or(file);
+    let name = name.strip_suffix(".txt").unwrap_or(name);
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("fixture_{}", name)
+    } else {
+        name
+    }
+}
@@ -0,0 +1,9 @@
+use std::path::Path;
+
+fn main() -> std::process::ExitCode {
+    aoc::runner(
+        aoc::YEAR,
+        Path::new(env!("CARGO_MANIFEST_DIR")),
+        aoc::days::registry(),
+    )
+}
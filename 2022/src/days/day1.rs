@@ -0,0 +1,83 @@
+use crate::{Answer, AoC, Error, Input, InputStream, Result, Solution, YEAR};
+
+// The calories carried by the elves carrying the most, most first
+#[derive(Debug)]
+pub struct TopElves([i64; 3]);
+
+// Before any elf, whoever comes first beats these
+impl Default for TopElves {
+    fn default() -> Self {
+        Self([i64::MIN; 3])
+    }
+}
+
+impl TopElves {
+    pub fn push(&mut self, calories: i64) {
+        if let Some(i) = self.0.iter().position(|&top| calories > top) {
+            self.0[i..].rotate_right(1);
+            self.0[i] = calories;
+        }
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Parsed = TopElves;
+    type Part1 = i64;
+    type Part2 = i64;
+    const STREAMS: bool = true;
+
+    fn parse(&self, input: Input) -> Result<Self::Parsed> {
+        self.parse_stream(&mut input.into())
+    }
+
+    // Only the running total of the current elf and the top 3 are kept
+    fn parse_stream(&self, input: &mut InputStream) -> Result<Self::Parsed> {
+        let mut top = TopElves::default();
+        let mut elves = 1;
+        let mut current = 0;
+        for line in input.lines() {
+            let (i, value) = line?;
+            if value.is_empty() {
+                top.push(current);
+                elves += 1;
+                current = 0;
+            } else {
+                let food_cal = value.parse::<i64>().map_err(|e| {
+                    Error::parse("Solver::parse", "Bad calories value")
+                        .found(&value, &value, "a number or an empty line")
+                        .on_line(i)
+                        .with_source(e)
+                })?;
+                current += food_cal;
+            }
+        }
+        top.push(current);
+
+        if elves < 3 {
+            return Err(Error::parse(
+                "Solver::parse",
+                "There must be at least 3 elves",
+            ));
+        }
+
+        Ok(top)
+    }
+
+    fn part1(&self, top: &Self::Parsed) -> Result<i64> {
+        Ok(top.0[0])
+    }
+
+    fn part2(&self, top: &Self::Parsed) -> Result<i64> {
+        Ok(top.0.iter().sum())
+    }
+}
+
+pub fn aoc() -> AoC<i64, i64> {
+    AoC::new(YEAR, 1)
+}
+
+pub fn get_answer(input: Input) -> Result<Answer<i64, i64>> {
+    Solver.solve(input)
+}
@@ -0,0 +1,272 @@
+use crate::{Answer, AoC, Input, Result, Solution, YEAR};
+
+pub mod handheld_device {
+    use crate::{Error, Input, Result};
+
+    #[derive(Debug)]
+    pub enum CpuInstruction {
+        Noop,
+        AddX(isize),
+    }
+
+    impl std::fmt::Display for CpuInstruction {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let name = match self {
+                CpuInstruction::Noop => "NOOP".to_string(),
+                CpuInstruction::AddX(x) => format!("ADD_X {}", x),
+            };
+            write!(f, "{}", name)
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct ProgramInstructions(Vec<CpuInstruction>);
+
+    impl std::fmt::Display for ProgramInstructions {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let instructions = &self.0;
+            for i in 0..instructions.len() {
+                write!(f, "{}", instructions[i]).unwrap();
+                if i != instructions.len() - 1 {
+                    writeln!(f).unwrap();
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl ProgramInstructions {
+        pub fn new(input: &Input) -> Result<Self> {
+            let bad_input = |i: usize, token: &str, expected: &str| {
+                input.error("ProgramInstructions::new", "Bad input", i, token, expected)
+            };
+            let mut program: Vec<CpuInstruction> = Vec::new();
+
+            for (i, line) in input.iter().enumerate() {
+                let parsed_line = line.split(' ').collect::<Vec<_>>();
+
+                if parsed_line[0] == "noop" {
+                    program.push(CpuInstruction::Noop);
+                    continue;
+                }
+
+                if parsed_line[0] == "addx" {
+                    if parsed_line.len() > 2 {
+                        return Err(bad_input(i, parsed_line[2], "the end of the line"));
+                    }
+                    let value_token = parsed_line.get(1).copied().unwrap_or_default();
+                    let value = match value_token.parse::<isize>() {
+                        Ok(v) => v,
+                        Err(e) => {
+                            return Err(bad_input(i, value_token, "a number").with_source(e));
+                        }
+                    };
+                    program.push(CpuInstruction::AddX(value));
+                    continue;
+                }
+
+                return Err(bad_input(i, parsed_line[0], "`noop` or `addx`"));
+            }
+
+            Ok(Self(program))
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct CpuRegisterRecord {
+        x: isize,
+    }
+
+    impl std::fmt::Display for CpuRegisterRecord {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "X: {}", self.x)
+        }
+    }
+
+    impl CpuRegisterRecord {
+        pub fn new(x: isize) -> Self {
+            Self { x }
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct Program(Vec<CpuRegisterRecord>);
+
+    impl std::fmt::Display for Program {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let program = &self.0;
+            for i in 0..program.len() {
+                write!(f, "{}: {}", i, program[i]).unwrap();
+                if i != program.len() {
+                    writeln!(f).unwrap();
+                }
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    pub struct CRTScreenOutput(pub Vec<Vec<char>>);
+
+    pub const LOWER_HALF_BLOCK: &str = "▃";
+    pub const DARK_BLOCK: &str = " ";
+    pub const LIGHT_BLOCK: &str = "▓";
+    pub const BLOCK: &str = "░";
+
+    // `{:#}` leaves out the monitor and draws the pixels with `#` and `.`,
+    // the same way FromStr reads them
+    impl std::fmt::Display for CRTScreenOutput {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let screen = &self.0;
+            let monitor_width = screen[0].len();
+
+            if f.alternate() {
+                let light = LIGHT_BLOCK.chars().next().unwrap();
+                let rows = screen
+                    .iter()
+                    .map(|line| {
+                        line.iter()
+                            .map(|c| if *c == light { '#' } else { '.' })
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<_>>();
+                return write!(f, "{}", rows.join("\n"));
+            }
+
+            writeln!(f, "\n{}", LOWER_HALF_BLOCK.repeat(monitor_width + 2)).unwrap();
+            for screen_line in screen {
+                writeln!(
+                    f,
+                    "{1}{0}{1}",
+                    screen_line.iter().cloned().collect::<String>(),
+                    BLOCK
+                )
+                .unwrap();
+            }
+            writeln!(f, "{}", BLOCK.repeat(monitor_width + 2)).unwrap();
+            let model = " Communication device 9000 ";
+            write!(
+                f,
+                "{2}{3}{3}{0}{1}{2}",
+                model,
+                LOWER_HALF_BLOCK.repeat(monitor_width - model.len() - 2),
+                BLOCK,
+                LOWER_HALF_BLOCK
+            )
+        }
+    }
+
+    // Reads a screen drawn with `#` for lit pixels and `.` for dark ones, the
+    // way the puzzle draws it
+    impl std::str::FromStr for CRTScreenOutput {
+        type Err = &'static str;
+
+        fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+            let light = LIGHT_BLOCK.chars().next().unwrap();
+            let dark = DARK_BLOCK.chars().next().unwrap();
+
+            s.lines()
+                .map(|line| {
+                    line.chars()
+                        .map(|c| match c {
+                            '#' => Ok(light),
+                            '.' => Ok(dark),
+                            c if c == light || c == dark => Ok(c),
+                            _ => Err("CRTScreenOutput::from_str::Pixels must be `#` or `.`"),
+                        })
+                        .collect()
+                })
+                .collect::<std::result::Result<_, _>>()
+                .map(CRTScreenOutput)
+        }
+    }
+
+    impl Program {
+        pub fn new(instructions: &ProgramInstructions) -> Self {
+            let initial_x = 1;
+            let mut program: Vec<CpuRegisterRecord> = vec![CpuRegisterRecord::new(initial_x)];
+            let instr = &instructions.0;
+            let mut curr_x = program[0].x;
+            for instruction in instr {
+                match instruction {
+                    CpuInstruction::Noop => {
+                        program.push(CpuRegisterRecord::new(curr_x));
+                    }
+                    CpuInstruction::AddX(v) => {
+                        program.push(CpuRegisterRecord::new(curr_x));
+                        program.push(CpuRegisterRecord::new(curr_x));
+                        curr_x += v;
+                    }
+                }
+            }
+            Self(program)
+        }
+
+        pub fn signal_strength(&self, cycle: usize) -> Result<isize> {
+            if cycle > self.0.len() - 1 {
+                return Err(Error::simulation(
+                    "Program::signal_strength",
+                    "Program does not run on that cycle",
+                ));
+            }
+            Ok(cycle as isize * self.0[cycle].x)
+        }
+
+        pub fn execute(&self) -> CRTScreenOutput {
+            let mut screen: Vec<Vec<char>> = Vec::new();
+            let mut screen_line: Vec<char> = Vec::new();
+            let program = &self.0;
+            for (cycle, record) in program.iter().enumerate().skip(1) {
+                let sprite_start = record.x - 1;
+                let sprite = sprite_start..=sprite_start + 2;
+                let pixel_pos = (cycle - 1) % 40;
+                let draw_pixel = sprite.contains(&(pixel_pos as isize));
+
+                screen_line.push(if draw_pixel {
+                    LIGHT_BLOCK.chars().next().unwrap()
+                } else {
+                    DARK_BLOCK.chars().next().unwrap()
+                });
+
+                if screen_line.len() == 40 {
+                    screen.push(screen_line.clone());
+                    screen_line = Vec::new();
+                }
+            }
+
+            CRTScreenOutput(screen)
+        }
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Parsed = handheld_device::Program;
+    type Part1 = isize;
+    type Part2 = handheld_device::CRTScreenOutput;
+
+    fn parse(&self, input: Input) -> Result<Self::Parsed> {
+        let instructions = handheld_device::ProgramInstructions::new(&input)?;
+        Ok(handheld_device::Program::new(&instructions))
+    }
+
+    fn part1(&self, program: &Self::Parsed) -> Result<isize> {
+        (20..=220)
+            .step_by(40)
+            .map(|cycle| program.signal_strength(cycle))
+            .sum()
+    }
+
+    fn part2(&self, program: &Self::Parsed) -> Result<handheld_device::CRTScreenOutput> {
+        Ok(program.execute())
+    }
+}
+
+pub fn aoc() -> AoC<isize, handheld_device::CRTScreenOutput> {
+    AoC::new(YEAR, 10)
+}
+
+pub fn get_answer(input: Input) -> Result<Answer<isize, handheld_device::CRTScreenOutput>> {
+    Solver.solve(input)
+}
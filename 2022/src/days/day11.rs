@@ -0,0 +1,254 @@
+use crate::{Answer, AoC, Input, Result, Solution, YEAR};
+
+pub mod mitm {
+    use std::collections::VecDeque;
+
+    use crate::{Input, Result};
+
+    #[derive(Debug, Clone)]
+    pub enum Operation {
+        Add(usize),
+        Sub(usize),
+        Mul(usize),
+        Pow,
+    }
+
+    impl Operation {
+        pub fn exec(&self, x: usize) -> usize {
+            match self {
+                Self::Add(v) => x + v,
+                Self::Sub(v) => x - v,
+                Self::Mul(v) => x * v,
+                Self::Pow => x * x,
+            }
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Monkey {
+        pub holding_items: VecDeque<usize>,
+        pub operation: Operation,
+        pub test_threshold: usize,
+        pub test_true: usize,
+        pub test_false: usize,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Monkeys(pub Vec<Monkey>);
+    impl std::fmt::Display for Monkeys {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let monkeys = &self.0;
+            let monkeys_len = monkeys.len();
+            for (i, monkey) in monkeys.iter().enumerate() {
+                write!(f, "{:?}", monkey).unwrap();
+                if i != monkeys_len - 1 {
+                    writeln!(f).unwrap();
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl Monkeys {
+        pub fn new(input: &Input) -> Result<Self> {
+            let invalid_input_msg = "Invalid input";
+            let mut monkeys: Vec<Monkey> = Vec::new();
+
+            // Returns what comes after `<name>: ` on the given line
+            let field = |line: usize, name: &str| -> Result<&str> {
+                let expected = format!("`{}: ...`", name);
+                let text = match input.get(line) {
+                    Some(v) => v,
+                    None => {
+                        return Err(input.error_at_end("Monkeys::new", invalid_input_msg, expected))
+                    }
+                };
+                match text.trim().split_once(": ") {
+                    Some((key, value)) if key == name => Ok(value),
+                    Some((key, _)) => {
+                        Err(input.error("Monkeys::new", invalid_input_msg, line, key, expected))
+                    }
+                    None => {
+                        Err(input.error("Monkeys::new", invalid_input_msg, line, text, expected))
+                    }
+                }
+            };
+            let parse_number = |line: usize, s: &str| {
+                s.parse::<usize>().map_err(|e| {
+                    input
+                        .error("Monkeys::new", invalid_input_msg, line, s, "a number")
+                        .with_source(e)
+                })
+            };
+            let last_number = |line: usize, s: &str| {
+                parse_number(line, s.split(' ').next_back().unwrap_or_default())
+            };
+
+            for i in (0..input.len()).step_by(7) {
+                let header = input[i].split(' ').next().unwrap_or_default();
+                if header != "Monkey" {
+                    return Err(input.error(
+                        "Monkeys::new",
+                        invalid_input_msg,
+                        i,
+                        header,
+                        "`Monkey`",
+                    ));
+                }
+
+                let holding_items = field(i + 1, "Starting items")?
+                    .split(", ")
+                    .map(|s| parse_number(i + 1, s))
+                    .collect::<Result<VecDeque<_>>>()?;
+
+                let operation_items: Vec<&str> = field(i + 2, "Operation")?.split(' ').collect();
+                let operation_items_len = operation_items.len();
+                if operation_items_len < 2 {
+                    return Err(input.error(
+                        "Monkeys::new",
+                        invalid_input_msg,
+                        i + 2,
+                        "",
+                        "an operation like `new = old * 19`",
+                    ));
+                }
+                let operation_val = operation_items[operation_items_len - 1];
+                let operation = if operation_val == "old" {
+                    Operation::Pow
+                } else {
+                    let v: usize = parse_number(i + 2, operation_val)?;
+                    let operator = operation_items[operation_items_len - 2];
+                    match operator {
+                        "+" => Operation::Add(v),
+                        "-" => Operation::Sub(v),
+                        "*" => Operation::Mul(v),
+                        _ => {
+                            return Err(input.error(
+                                "Monkeys::new",
+                                invalid_input_msg,
+                                i + 2,
+                                operator,
+                                "one of +, - or *",
+                            ))
+                        }
+                    }
+                };
+
+                let test_threshold = last_number(i + 3, field(i + 3, "Test")?)?;
+                let test_true = last_number(i + 4, field(i + 4, "If true")?)?;
+                let test_false = last_number(i + 5, field(i + 5, "If false")?)?;
+
+                monkeys.push(Monkey {
+                    holding_items,
+                    operation,
+                    test_threshold,
+                    test_true,
+                    test_false,
+                })
+            }
+
+            if monkeys.len() < 2 {
+                return Err(input.error_at_end(
+                    "Monkeys::new",
+                    "There must be at least 2 monkeys",
+                    "another monkey",
+                ));
+            }
+            for (m, monkey) in monkeys.iter().enumerate() {
+                for (offset, target) in [(4, monkey.test_true), (5, monkey.test_false)] {
+                    if target >= monkeys.len() {
+                        let line = m * 7 + offset;
+                        return Err(input.error(
+                            "Monkeys::new",
+                            "Monkeys can only throw to existing monkeys",
+                            line,
+                            input[line].split(' ').next_back().unwrap_or_default(),
+                            format!("a monkey between 0 and {}", monkeys.len() - 1),
+                        ));
+                    }
+                }
+            }
+
+            Ok(Monkeys(monkeys))
+        }
+
+        pub fn inspect(&mut self, rounds: usize, relieved: bool) -> Vec<usize> {
+            let monkeys = &mut self.0;
+            // All the test_thresholds are relatively prime and the Least Common
+            // Multiple of relatively prime numbers is just their product
+            let lcm: usize = monkeys.iter().map(|m| m.test_threshold).product();
+            let monkeys_len = monkeys.len();
+            let mut monkeys_inspections_count = vec![0; monkeys_len];
+            for _ in 0..rounds {
+                for monkey_i in 0..monkeys_len {
+                    let inspections_count = monkeys[monkey_i].holding_items.len();
+                    for _ in 0..inspections_count {
+                        let mut item_worry_level =
+                            monkeys[monkey_i].holding_items.pop_front().unwrap();
+                        item_worry_level = monkeys[monkey_i].operation.exec(item_worry_level);
+
+                        // We need to keep the item_worry_level low or weird
+                        // things start to happen when testing it against the
+                        // test_threshold. To achieve that we can mod them
+                        // against their LCM
+                        item_worry_level %= lcm;
+
+                        if !relieved {
+                            item_worry_level /= 3;
+                        }
+
+                        let passed_test =
+                            item_worry_level.is_multiple_of(monkeys[monkey_i].test_threshold);
+
+                        let receiving_monkey_index = if passed_test {
+                            monkeys[monkey_i].test_true
+                        } else {
+                            monkeys[monkey_i].test_false
+                        };
+
+                        monkeys[receiving_monkey_index]
+                            .holding_items
+                            .push_back(item_worry_level);
+                    }
+                    monkeys_inspections_count[monkey_i] += inspections_count;
+                }
+            }
+            monkeys_inspections_count
+        }
+
+        pub fn monkey_business(&mut self, rounds: usize, relieved: bool) -> usize {
+            let monkeys_len = self.0.len();
+            let mut monkeys_inspections_count = self.inspect(rounds, relieved);
+            monkeys_inspections_count.sort();
+            monkeys_inspections_count[monkeys_len - 1] * monkeys_inspections_count[monkeys_len - 2]
+        }
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Parsed = mitm::Monkeys;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: Input) -> Result<Self::Parsed> {
+        mitm::Monkeys::new(&input)
+    }
+
+    fn part1(&self, monkeys: &Self::Parsed) -> Result<usize> {
+        Ok(monkeys.clone().monkey_business(20, false))
+    }
+
+    fn part2(&self, monkeys: &Self::Parsed) -> Result<usize> {
+        Ok(monkeys.clone().monkey_business(10000, true))
+    }
+}
+
+pub fn aoc() -> AoC<usize, usize> {
+    AoC::new(YEAR, 11)
+}
+
+pub fn get_answer(input: Input) -> Result<Answer<usize, usize>> {
+    Solver.solve(input)
+}
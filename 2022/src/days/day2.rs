@@ -0,0 +1,140 @@
+use crate::{Answer, AoC, Error, Input, InputStream, Result, Solution, YEAR};
+
+#[derive(Clone, Copy)]
+pub enum GameMove {
+    Rock = 1,
+    Paper,
+    Scissors,
+}
+
+#[derive(Clone, Copy)]
+pub enum GameResult {
+    Win = 6,
+    Lose = 0,
+    Draw = 3,
+}
+
+pub fn get_game_result(player_a_move: GameMove, player_b_move: GameMove) -> GameResult {
+    match (player_a_move, player_b_move) {
+        (GameMove::Rock, GameMove::Scissors)
+        | (GameMove::Paper, GameMove::Rock)
+        | (GameMove::Scissors, GameMove::Paper) => GameResult::Win,
+        (GameMove::Rock, GameMove::Rock)
+        | (GameMove::Paper, GameMove::Paper)
+        | (GameMove::Scissors, GameMove::Scissors) => GameResult::Draw,
+        _ => GameResult::Lose,
+    }
+}
+
+pub fn get_game_move(game_result: GameResult, player_b_move: GameMove) -> GameMove {
+    match (game_result, player_b_move) {
+        (GameResult::Draw, GameMove::Rock)
+        | (GameResult::Win, GameMove::Scissors)
+        | (GameResult::Lose, GameMove::Paper) => GameMove::Rock,
+        (GameResult::Draw, GameMove::Paper)
+        | (GameResult::Win, GameMove::Rock)
+        | (GameResult::Lose, GameMove::Scissors) => GameMove::Paper,
+        _ => GameMove::Scissors,
+    }
+}
+
+// A line of the strategy guide. The second column is read as a move for part
+// 1 and as the result the round should have for part 2
+pub struct Round {
+    pub opponent_move: GameMove,
+    pub my_move: GameMove,
+    pub game_result_prediction: GameResult,
+}
+
+impl Round {
+    // Reading the second column as my move
+    pub fn move_score(&self) -> i64 {
+        self.my_move as i64 + get_game_result(self.my_move, self.opponent_move) as i64
+    }
+
+    // Reading it as the result, with the move that gets it
+    pub fn result_score(&self) -> i64 {
+        let my_real_move = get_game_move(self.game_result_prediction, self.opponent_move);
+        my_real_move as i64 + self.game_result_prediction as i64
+    }
+}
+
+// The total score of the strategy guide read each way
+#[derive(Debug, Default)]
+pub struct Scores {
+    pub moves: i64,
+    pub results: i64,
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Parsed = Scores;
+    type Part1 = i64;
+    type Part2 = i64;
+    const STREAMS: bool = true;
+
+    fn parse(&self, input: Input) -> Result<Self::Parsed> {
+        self.parse_stream(&mut input.into())
+    }
+
+    // Rounds are scored as they're read, none of them are kept
+    fn parse_stream(&self, input: &mut InputStream) -> Result<Self::Parsed> {
+        let mut scores = Scores::default();
+
+        for line in input.lines() {
+            let (i, prediction) = line?;
+            let mut iter = prediction.split_whitespace();
+
+            let opponent_guess = iter.next().unwrap_or_default();
+            let opponent_move = match opponent_guess {
+                "A" => GameMove::Rock,
+                "B" => GameMove::Paper,
+                "C" => GameMove::Scissors,
+                _ => {
+                    return Err(Error::parse("Solver::parse", "Invalid move from opponent!")
+                        .found(&prediction, opponent_guess, "one of A, B or C")
+                        .on_line(i))
+                }
+            };
+
+            let guess = iter.next().unwrap_or_default();
+            let (my_move, game_result_prediction) = match guess {
+                "X" => (GameMove::Rock, GameResult::Lose),
+                "Y" => (GameMove::Paper, GameResult::Draw),
+                "Z" => (GameMove::Scissors, GameResult::Win),
+                _ => {
+                    return Err(Error::parse("Solver::parse", "Invalid move from me!")
+                        .found(&prediction, guess, "one of X, Y or Z")
+                        .on_line(i))
+                }
+            };
+
+            let round = Round {
+                opponent_move,
+                my_move,
+                game_result_prediction,
+            };
+            scores.moves += round.move_score();
+            scores.results += round.result_score();
+        }
+
+        Ok(scores)
+    }
+
+    fn part1(&self, scores: &Self::Parsed) -> Result<i64> {
+        Ok(scores.moves)
+    }
+
+    fn part2(&self, scores: &Self::Parsed) -> Result<i64> {
+        Ok(scores.results)
+    }
+}
+
+pub fn aoc() -> AoC<i64, i64> {
+    AoC::new(YEAR, 2)
+}
+
+pub fn get_answer(input: Input) -> Result<Answer<i64, i64>> {
+    Solver.solve(input)
+}
@@ -0,0 +1,179 @@
+use crate::{Answer, AoC, Error, Input, Result, Solution, YEAR};
+
+#[derive(Clone)]
+pub struct Rucksack {
+    left_compartment: String,
+    right_compartment: String,
+}
+
+pub trait RucksackTrait {
+    type Item;
+    type Priority;
+
+    fn new(s: &str) -> Result<Self>
+    where
+        Self: Sized;
+    fn get_item_priority(item: Self::Item) -> Result<Self::Priority>;
+
+    fn get_items(&mut self) -> (Vec<Self::Item>, Vec<Self::Item>);
+    fn get_common_item(&mut self) -> Result<Self::Item>;
+
+    fn get_group_common_item(group: &[Self]) -> Result<Self::Item>
+    where
+        Self: Sized;
+}
+
+impl RucksackTrait for Rucksack {
+    type Item = char;
+    type Priority = i32;
+
+    fn new(s: &str) -> Result<Self> {
+        if let Some((i, c)) = s.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            return Err(Error::parse("Rucksack::new", "Invalid item").found(
+                s,
+                &s[i..i + c.len_utf8()],
+                "an item from a to z or A to Z",
+            ));
+        }
+
+        let (left_compartment, right_compartment) = s.split_at(s.len() / 2);
+        if left_compartment.len() != right_compartment.len() {
+            return Err(Error::parse(
+                "Rucksack::new",
+                "Left and right compartments must have the same length",
+            )
+            .found(s, s, "an even number of items"));
+        }
+
+        Ok(Rucksack {
+            left_compartment: left_compartment.to_string(),
+            right_compartment: right_compartment.to_string(),
+        })
+    }
+
+    fn get_item_priority(item: Self::Item) -> Result<i32> {
+        let lowercase_a_value = 'a' as i32;
+        let uppercase_a_value = 'A' as i32;
+        let item_value = item as i32;
+
+        match item {
+            'a'..='z' => Ok(item_value - lowercase_a_value + 1),
+            'A'..='Z' => Ok(item_value - uppercase_a_value + 27),
+            _ => Err(Error::parse("Rucksack::get_item_priority", "Invalid char")),
+        }
+    }
+
+    fn get_items(&mut self) -> (Vec<char>, Vec<char>) {
+        (
+            self.left_compartment
+                .chars()
+                .collect::<Vec<Self::Item>>()
+                .clone(),
+            self.right_compartment
+                .chars()
+                .collect::<Vec<Self::Item>>()
+                .clone(),
+        )
+    }
+
+    fn get_common_item(&mut self) -> Result<Self::Item> {
+        let (left_compartment_items, right_compartment_items) = self.get_items();
+        let mut common_item: Option<Self::Item> = None;
+
+        left_compartment_items.iter().for_each(|l_item| {
+            right_compartment_items.iter().for_each(|r_item| {
+                if l_item == r_item {
+                    common_item = Some(*l_item);
+                }
+            })
+        });
+
+        common_item.ok_or(Error::simulation(
+            "Rucksack::get_common_item",
+            "There is no common item",
+        ))
+    }
+
+    fn get_group_common_item(group: &[Self]) -> Result<Self::Item> {
+        if group.len() != 3 {
+            return Err(Error::parse(
+                "Rucksack::get_group_common_item",
+                "A group must have 3 elements",
+            ));
+        }
+        let mut common_item: Option<Self::Item> = None;
+
+        let (first_elf_left, first_elf_right) = group[0].clone().get_items();
+        let has_item = |rucksack: &Self, item: char| {
+            rucksack.left_compartment.contains(item) || rucksack.right_compartment.contains(item)
+        };
+
+        for item in first_elf_left.into_iter().chain(first_elf_right) {
+            if has_item(&group[1], item) && has_item(&group[2], item) {
+                common_item = Some(item);
+                break;
+            }
+        }
+
+        common_item.ok_or(Error::simulation(
+            "Rucksack::get_group_common_item",
+            "There is no common item",
+        ))
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Parsed = Vec<Rucksack>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: Input) -> Result<Self::Parsed> {
+        let rucksacks = input
+            .iter()
+            .enumerate()
+            .map(|(i, s)| Rucksack::new(s).map_err(|e| e.on_line(i)))
+            .collect::<Result<Vec<_>>>()?;
+
+        if !input.len().is_multiple_of(3) {
+            return Err(input.error_at_end(
+                "Solver::parse",
+                "Can't make groups of 3",
+                format!("{} more rucksacks", 3 - input.len() % 3),
+            ));
+        }
+
+        Ok(rucksacks)
+    }
+
+    fn part1(&self, rucksacks: &Self::Parsed) -> Result<i32> {
+        let mut common_items_priority = 0;
+
+        for rucksack in rucksacks {
+            let common_item = rucksack.clone().get_common_item()?;
+            common_items_priority += Rucksack::get_item_priority(common_item)?;
+        }
+
+        Ok(common_items_priority)
+    }
+
+    fn part2(&self, rucksacks: &Self::Parsed) -> Result<i32> {
+        let mut group_common_items_priority = 0;
+
+        for group in rucksacks.chunks(3) {
+            group_common_items_priority +=
+                Rucksack::get_item_priority(Rucksack::get_group_common_item(group)?)?
+        }
+
+        Ok(group_common_items_priority)
+    }
+}
+
+pub fn aoc() -> AoC<i32, i32> {
+    AoC::new(YEAR, 3)
+}
+
+pub fn get_answer(input: Input) -> Result<Answer<i32, i32>> {
+    Solver.solve(input)
+}
@@ -0,0 +1,102 @@
+use crate::{Answer, AoC, Input, Result, Solution, YEAR};
+
+pub mod elf_pair {
+    use std::ops::RangeInclusive;
+
+    use crate::{Error, Result};
+
+    #[derive(Debug)]
+    pub struct ElfPair(pub RangeInclusive<i32>, pub RangeInclusive<i32>);
+
+    impl ElfPair {
+        pub fn new(input: &str) -> Result<Self> {
+            let elves: Vec<&str> = input.split(',').collect();
+            if elves.len() != 2 {
+                return Err(
+                    Error::parse("ElfPair::new", "There must be 2 elves in each input").found(
+                        input,
+                        input,
+                        "two ranges separated by a comma",
+                    ),
+                );
+            }
+
+            Ok(ElfPair(
+                Self::get_range(input, elves[0])?,
+                Self::get_range(input, elves[1])?,
+            ))
+        }
+
+        pub fn fully_contains(&self) -> bool {
+            self.1.end() <= self.0.end() && self.1.start() >= self.0.start()
+                || self.0.end() <= self.1.end() && self.1.start() <= self.0.start()
+        }
+
+        pub fn partially_contains(&self) -> bool {
+            self.1.end() >= self.0.start() && self.0.end() >= self.1.start()
+        }
+
+        // `s` is the part of `line` holding a single range
+        fn get_range(line: &str, s: &str) -> Result<RangeInclusive<i32>> {
+            let elf_range: Vec<&str> = s.split('-').collect();
+
+            let wrong_input = |token: &str, expected: &str| {
+                Error::parse("ElfPair::get_range", "Wrongly formatted input")
+                    .found(line, token, expected)
+            };
+            if elf_range.len() != 2 {
+                return Err(wrong_input(s, "a range like 2-4"));
+            }
+
+            let range_val_1 = match elf_range[0].parse::<i32>() {
+                Ok(val) => val,
+                Err(e) => return Err(wrong_input(elf_range[0], "a number").with_source(e)),
+            };
+            let range_val_2 = match elf_range[1].parse::<i32>() {
+                Ok(val) => val,
+                Err(e) => return Err(wrong_input(elf_range[1], "a number").with_source(e)),
+            };
+
+            if range_val_2 < range_val_1 {
+                return Err(wrong_input(s, "a range that doesn't end before it starts"));
+            }
+
+            Ok(range_val_1..=range_val_2)
+        }
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Parsed = Vec<elf_pair::ElfPair>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: Input) -> Result<Self::Parsed> {
+        input
+            .iter()
+            .enumerate()
+            .map(|(i, a)| elf_pair::ElfPair::new(a).map_err(|e| e.on_line(i)))
+            .collect()
+    }
+
+    fn part1(&self, assignments: &Self::Parsed) -> Result<usize> {
+        Ok(assignments.iter().filter(|x| x.fully_contains()).count())
+    }
+
+    fn part2(&self, assignments: &Self::Parsed) -> Result<usize> {
+        Ok(assignments
+            .iter()
+            .filter(|x| x.partially_contains())
+            .count())
+    }
+}
+
+pub fn aoc() -> AoC<usize, usize> {
+    AoC::new(YEAR, 4)
+}
+
+pub fn get_answer(input: Input) -> Result<Answer<usize, usize>> {
+    Solver.solve(input)
+}
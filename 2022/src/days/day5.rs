@@ -0,0 +1,238 @@
+use crate::{Answer, AoC, Input, Normalization, Result, Solution, Whitespace, YEAR};
+
+pub mod supply_stacks {
+    use crate::{Error, Input, Result};
+
+    #[derive(Debug)]
+    pub struct DrawingComponents {
+        pub stacks: Vec<String>,
+        pub instructions: Vec<String>,
+        // Index of the first instruction in the input
+        pub instructions_start: usize,
+    }
+
+    impl DrawingComponents {
+        pub fn new(input: &Input) -> Result<Self> {
+            let split_index = match input.iter().position(|a| a.is_empty()) {
+                Some(val) => val,
+                None => {
+                    return Err(input.error_at_end(
+                        "DrawingComponents::new",
+                        "Bad format",
+                        "an empty line between the drawing and the instructions",
+                    ))
+                }
+            };
+
+            Ok(Self {
+                stacks: input[..split_index].to_vec(),
+                instructions: input[split_index + 1..].to_vec(),
+                instructions_start: split_index + 1,
+            })
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct SupplyStacks(Vec<Vec<char>>);
+
+    impl std::fmt::Display for SupplyStacks {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            for (i, char_vec) in self.0.iter().enumerate() {
+                write!(f, "{}: ", i + 1).unwrap();
+
+                for char in char_vec {
+                    write!(f, "[{}] ", char).unwrap();
+                }
+
+                if i + 1 < self.0.len() {
+                    writeln!(f).unwrap();
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    impl SupplyStacks {
+        pub fn new(stacks_drawing: Vec<String>) -> Result<Self> {
+            let mut stacks_drawing_clone = stacks_drawing.clone();
+
+            if stacks_drawing_clone.len() < 2 {
+                return Err(Error::parse("SupplyStacks::new", "You have no stacks"));
+            }
+
+            let stack_numbers = stacks_drawing_clone.pop().unwrap();
+            let last_stack_number = stack_numbers.split("   ").last().unwrap().trim();
+            let stack_count: usize = match last_stack_number.parse() {
+                Ok(val) => val,
+                Err(e) => {
+                    return Err(Error::parse(
+                        "SupplyStacks::new",
+                        "Could not get stack count from drawing",
+                    )
+                    .found(&stack_numbers, last_stack_number, "the stack numbers")
+                    .on_line(stacks_drawing_clone.len())
+                    .with_source(e))
+                }
+            };
+
+            let mut stacks: Vec<Vec<char>> = Vec::new();
+
+            for _ in 0..stack_count {
+                stacks.push(Vec::new())
+            }
+
+            for line in stacks_drawing_clone.iter().rev() {
+                for (i, stack) in stacks.iter_mut().enumerate() {
+                    // Editors and browsers trim the spaces at the end of a
+                    // line, the stacks past it have no crate there
+                    let Some(crate_label) = line.chars().nth((i * 4) + 1) else {
+                        break;
+                    };
+                    if crate_label != ' ' {
+                        stack.push(crate_label);
+                    }
+                }
+            }
+
+            Ok(Self(stacks))
+        }
+
+        pub fn operate_crane(&mut self, instructions: &[String], model: usize) -> Result<&Self> {
+            for (i, instruction) in instructions.iter().enumerate() {
+                let bad_instruction = |token: &str, expected: &str| {
+                    Error::parse("SupplyStacks::operate_crane", "Bad instruction")
+                        .found(instruction, token, expected)
+                        .on_line(i)
+                };
+
+                let instruction_items: Vec<&str> = instruction.split(" ").collect();
+                if instruction_items.len() != 6 {
+                    return Err(bad_instruction(
+                        instruction,
+                        "an instruction like `move 1 from 2 to 1`",
+                    ));
+                }
+
+                let quantity_to_move = match instruction_items[1].parse::<usize>() {
+                    Ok(v) => v,
+                    Err(e) => {
+                        return Err(
+                            bad_instruction(instruction_items[1], "a crate count").with_source(e)
+                        )
+                    }
+                };
+                let origin = match instruction_items[3].parse::<usize>() {
+                    Ok(v) => v.wrapping_sub(1),
+                    Err(e) => {
+                        return Err(
+                            bad_instruction(instruction_items[3], "a stack number").with_source(e)
+                        )
+                    }
+                };
+                let destination: usize = match instruction_items[5].parse::<usize>() {
+                    Ok(v) => v.wrapping_sub(1),
+                    Err(e) => {
+                        return Err(
+                            bad_instruction(instruction_items[5], "a stack number").with_source(e)
+                        )
+                    }
+                };
+
+                let stacks = &mut self.0;
+                if origin >= stacks.len() || destination >= stacks.len() {
+                    return Err(Error::simulation(
+                        "SupplyStacks::operate_crane",
+                        "There is no such stack",
+                    ));
+                }
+                match model {
+                    // Moves one crate
+                    9000 => {
+                        for _ in 0..quantity_to_move {
+                            let moving_crate = match stacks[origin].pop() {
+                                Some(v) => v,
+                                None => return Err(Error::simulation("SupplyStacks::operate_crane", "[CrateMover 9000]The stack you're trying to move a crate from is empty")),
+                            };
+                            stacks[destination].push(moving_crate);
+                        }
+                    }
+                    // Moves multiple crates at once
+                    9001 => {
+                        let origin_size = stacks[origin].len();
+                        if origin_size < quantity_to_move {
+                            return Err(Error::simulation("SupplyStacks::operate_crane", "[CrateMover 9001]The stack you're trying to move crates from doesn't have enough crates"));
+                        }
+                        let mut moving_crates =
+                            stacks[origin].split_off(origin_size - quantity_to_move);
+                        stacks[destination].append(&mut moving_crates);
+                    }
+                    _ => {
+                        return Err(Error::simulation(
+                            "SupplyStacks::operate_crane",
+                            "We don't have that crane model available",
+                        ))
+                    }
+                }
+            }
+
+            Ok(self)
+        }
+
+        pub fn list_top_crates(&self) -> String {
+            self.0.iter().map(|stack| stack.last().unwrap()).collect()
+        }
+
+        #[allow(dead_code)]
+        pub fn display(&self) -> &Self {
+            println!("{}\n", self);
+            self
+        }
+    }
+}
+pub struct Solver;
+
+impl Solution for Solver {
+    type Parsed = (
+        supply_stacks::DrawingComponents,
+        supply_stacks::SupplyStacks,
+    );
+    type Part1 = String;
+    type Part2 = String;
+    // The drawing's columns are found by position, so its padding stays
+    const NORMALIZATION: Normalization = Normalization {
+        whitespace: Whitespace::Keep,
+        ..Normalization::DEFAULT
+    };
+
+    fn parse(&self, input: Input) -> Result<Self::Parsed> {
+        let drawing_contents = supply_stacks::DrawingComponents::new(&input)?;
+        let supply_stacks = supply_stacks::SupplyStacks::new(drawing_contents.stacks.clone())?;
+
+        Ok((drawing_contents, supply_stacks))
+    }
+
+    fn part1(&self, (drawing_contents, supply_stacks): &Self::Parsed) -> Result<String> {
+        Ok(supply_stacks
+            .clone()
+            .operate_crane(&drawing_contents.instructions, 9000)
+            .map_err(|e| e.shift_lines(drawing_contents.instructions_start))?
+            .list_top_crates())
+    }
+
+    fn part2(&self, (drawing_contents, supply_stacks): &Self::Parsed) -> Result<String> {
+        Ok(supply_stacks
+            .clone()
+            .operate_crane(&drawing_contents.instructions, 9001)
+            .map_err(|e| e.shift_lines(drawing_contents.instructions_start))?
+            .list_top_crates())
+    }
+}
+
+pub fn aoc() -> AoC<String, String> {
+    AoC::new(YEAR, 5)
+}
+
+pub fn get_answer(input: Input) -> Result<Answer<String, String>> {
+    Solver.solve(input)
+}
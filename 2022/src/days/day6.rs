@@ -0,0 +1,208 @@
+use crate::{Answer, AoC, Input, InputStream, Result, Solution, YEAR};
+
+pub mod communication_system {
+    use crate::{Error, Input, InputStream, Result};
+
+    #[derive(Clone, Copy, Debug)]
+    pub enum MarkerType {
+        StartOfPacket = 4,
+        StartOfMessage = 14,
+    }
+
+    impl MarkerType {
+        pub const VALUES: [Self; 2] = [Self::StartOfPacket, Self::StartOfMessage];
+    }
+
+    // How much of the signal is kept while it's scanned, the longest marker
+    const WINDOW: usize = MarkerType::StartOfMessage as usize;
+
+    // Where each marker ends in the signal. The signal itself isn't kept, it
+    // can be as long as it likes
+    #[derive(Debug, Default)]
+    pub struct Signal {
+        start_of_packet: Option<usize>,
+        start_of_message: Option<usize>,
+    }
+
+    // The last WINDOW characters read, in a ring
+    #[derive(Default)]
+    struct Window {
+        chars: [u8; WINDOW],
+        // How many were read in all
+        len: usize,
+    }
+
+    impl Window {
+        fn push(&mut self, c: u8) {
+            self.chars[self.len % WINDOW] = c;
+            self.len += 1;
+        }
+
+        // Whether the last `n` characters are all different
+        fn ends_with_marker(&self, n: usize) -> bool {
+            if self.len < n {
+                return false;
+            }
+            let mut seen = 0u32;
+            for i in self.len - n..self.len {
+                let bit = 1 << (self.chars[i % WINDOW] - b'a');
+                if seen & bit != 0 {
+                    return false;
+                }
+                seen |= bit;
+            }
+            true
+        }
+
+        // The characters it holds, oldest first
+        fn text(&self) -> String {
+            (self.len.saturating_sub(WINDOW)..self.len)
+                .map(|i| self.chars[i % WINDOW] as char)
+                .collect()
+        }
+    }
+
+    // Scanning state between reads
+    #[derive(Default)]
+    struct Scan {
+        window: Window,
+        signal: Signal,
+        // The whitespace read since the last letter, which is fine only at
+        // the end of the line, where it is normalized away
+        whitespace: usize,
+        first_whitespace: char,
+        // The last whitespace read was a `\r`
+        cr: bool,
+    }
+
+    impl Scan {
+        // Scans what was read until the end of the line. How many bytes it
+        // used and whether the line ended
+        fn bytes(&mut self, buffer: &[u8]) -> Result<(usize, bool)> {
+            for (i, &c) in buffer.iter().enumerate() {
+                match c {
+                    b'\n' => return Ok((i + 1, true)),
+                    _ if c.is_ascii_whitespace() => {
+                        if self.whitespace == 0 {
+                            self.first_whitespace = c as char;
+                        }
+                        self.whitespace += 1;
+                        self.cr = c == b'\r';
+                    }
+                    _ if self.whitespace > 0 => return Err(self.bad_char(self.first_whitespace)),
+                    b'a'..=b'z' => {
+                        self.window.push(c);
+                        for marker in MarkerType::VALUES {
+                            let end = self.signal.end_mut(marker);
+                            if end.is_none() && self.window.ends_with_marker(marker as usize) {
+                                *end = Some(self.window.len);
+                            }
+                        }
+                    }
+                    _ => {
+                        let rest = &buffer[i..buffer.len().min(i + 4)];
+                        let c = String::from_utf8_lossy(rest).chars().next().unwrap();
+                        return Err(self.bad_char(c));
+                    }
+                }
+            }
+            Ok((buffer.len(), false))
+        }
+
+        // Points at `c` after what's left of the signal before it, in the
+        // column it has in the whole line
+        fn bad_char(&self, c: char) -> Error {
+            let before = self.window.text();
+            let line = format!("{}{}", before, c);
+            Error::parse("Signal::new", "Bad input")
+                .found(&line, &line[before.len()..], "a lowercase letter")
+                .on_line(0)
+                .shift_columns(self.window.len - before.len())
+        }
+    }
+
+    impl Signal {
+        // Finds the markers as the signal is read, the input is a single line
+        pub fn new(input: &mut InputStream) -> Result<Self> {
+            let mut scan = Scan::default();
+            let (mut read_any, mut ended) = (false, false);
+            while !ended {
+                let reader = input.reader();
+                let buffer = match reader.fill_buf() {
+                    Ok(buffer) => buffer,
+                    Err(e) => return Err(input.io_error(e)),
+                };
+                if buffer.is_empty() {
+                    break;
+                }
+                read_any = true;
+                let (used, line_ended) = scan.bytes(buffer)?;
+                reader.consume(used);
+                ended = line_ended;
+            }
+
+            if !read_any {
+                return Err(Input::default().error_at_end("Signal::new", "Bad input", "a signal"));
+            }
+            if !input.clean_line_end(scan.whitespace, scan.cr) {
+                return Err(scan.bad_char(scan.first_whitespace));
+            }
+            if let Some(line) = input.lines().next() {
+                let (_, line) = line?;
+                return Err(Error::parse("Signal::new", "Bad input")
+                    .found(&line, &line, "the signal to fit in a single line")
+                    .on_line(1));
+            }
+
+            Ok(scan.signal)
+        }
+
+        fn end_mut(&mut self, marker: MarkerType) -> &mut Option<usize> {
+            match marker {
+                MarkerType::StartOfPacket => &mut self.start_of_packet,
+                MarkerType::StartOfMessage => &mut self.start_of_message,
+            }
+        }
+
+        pub fn get_start_of_packet_pos(&self, marker: MarkerType) -> Result<usize> {
+            let end = match marker {
+                MarkerType::StartOfPacket => self.start_of_packet,
+                MarkerType::StartOfMessage => self.start_of_message,
+            };
+            end.ok_or_else(|| Error::simulation("Signal::get_start_of_packet_pos", "Bad signal"))
+        }
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Parsed = communication_system::Signal;
+    type Part1 = usize;
+    type Part2 = usize;
+    const STREAMS: bool = true;
+
+    fn parse(&self, input: Input) -> Result<Self::Parsed> {
+        self.parse_stream(&mut input.into())
+    }
+
+    fn parse_stream(&self, input: &mut InputStream) -> Result<Self::Parsed> {
+        communication_system::Signal::new(input)
+    }
+
+    fn part1(&self, signal: &Self::Parsed) -> Result<usize> {
+        signal.get_start_of_packet_pos(communication_system::MarkerType::StartOfPacket)
+    }
+
+    fn part2(&self, signal: &Self::Parsed) -> Result<usize> {
+        signal.get_start_of_packet_pos(communication_system::MarkerType::StartOfMessage)
+    }
+}
+
+pub fn aoc() -> AoC<usize, usize> {
+    AoC::new(YEAR, 6)
+}
+
+pub fn get_answer(input: Input) -> Result<Answer<usize, usize>> {
+    Solver.solve(input)
+}
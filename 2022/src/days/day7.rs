@@ -0,0 +1,211 @@
+use crate::{Answer, AoC, Error, Input, Result, Solution, YEAR};
+
+pub mod device {
+    use std::collections::HashSet;
+
+    use crate::{Input, Result};
+
+    pub const TOTAL_SPACE: usize = 70000000;
+    pub const MIN_FREE_SPACE_FOR_UPGRADE: usize = 30000000;
+
+    #[derive(Debug)]
+    pub struct File {
+        pub absolute_path: Vec<String>,
+        pub size: usize,
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct Directory(pub String, pub usize);
+
+    #[derive(Debug)]
+    pub struct DirectoryCollection(pub Vec<Directory>);
+
+    #[derive(Debug)]
+    pub struct FileSystem(Vec<File>);
+
+    impl std::fmt::Display for FileSystem {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.0.iter().for_each(|file| {
+                let mut file_path = String::from("/");
+                file_path.push_str(&file.absolute_path.join("/"));
+                writeln!(f, "{} ({})", file_path, file.size).unwrap();
+            });
+
+            Ok(())
+        }
+    }
+
+    impl FileSystem {
+        pub fn new(commands: &Input) -> Result<Self> {
+            let mut fs = Self(Vec::new());
+
+            let mut curr_path: Vec<&str> = Vec::new();
+            for (i, command) in commands.iter().enumerate() {
+                let command_args = command.split(" ").collect::<Vec<&str>>();
+                let args_count = command_args.len();
+
+                if args_count == 3 {
+                    match command_args[args_count - 1] {
+                        ".." => match curr_path.pop() {
+                            Some(_) => {}
+                            None => {
+                                return Err(commands.error(
+                                    "FileSystem::new",
+                                    "Cannot move out from the outermost directory",
+                                    i,
+                                    command_args[args_count - 1],
+                                    "a directory to move into",
+                                ))
+                            }
+                        },
+                        "/" => curr_path = Vec::new(),
+                        x => curr_path.push(x),
+                    }
+                }
+
+                match command_args[0] {
+                    "$" => {}
+                    "dir" => {}
+                    size => {
+                        let file_size = match size.parse::<usize>() {
+                            Ok(v) => v,
+                            Err(e) => {
+                                return Err(commands
+                                    .error(
+                                        "FileSystem::new",
+                                        "File size is not parseable",
+                                        i,
+                                        size,
+                                        "`$`, `dir` or a file size",
+                                    )
+                                    .with_source(e))
+                            }
+                        };
+                        if args_count != 2 {
+                            return Err(commands.error(
+                                "FileSystem::new",
+                                "File has no name",
+                                i,
+                                "",
+                                "a file name",
+                            ));
+                        }
+
+                        let mut file_absolute_path: Vec<String> =
+                            curr_path.iter().map(|x| x.to_string()).collect();
+                        file_absolute_path.push(command_args[1].to_string());
+
+                        fs.0.push(File {
+                            absolute_path: file_absolute_path,
+                            size: file_size,
+                        })
+                    }
+                }
+            }
+
+            Ok(fs)
+        }
+
+        pub fn ls_dirs(&self, max_size: Option<usize>) -> DirectoryCollection {
+            let fs = &self.0;
+            let mut dirs_vec: Vec<String> = Vec::new();
+
+            for file in fs {
+                let file_path = &file.absolute_path;
+                let file_path_len = file_path.len();
+                if file_path_len == 1 {
+                    continue;
+                }
+
+                for index in 0..file_path_len {
+                    let p = file_path[0..=index].join("/");
+                    dirs_vec.push(p);
+                }
+            }
+
+            let dirs_hashset = dirs_vec.iter().collect::<HashSet<_>>();
+
+            let mut dirs: DirectoryCollection = DirectoryCollection(Vec::new());
+
+            for dir in dirs_hashset {
+                let mut dir_size: usize = 0;
+                for file in fs {
+                    let file_path = &file.absolute_path;
+                    let joined_path = file_path[0..file_path.len()].join("/");
+                    if joined_path.len() > dir.len() && joined_path[0..dir.len()] == **dir {
+                        dir_size += file.size;
+                    }
+                }
+                if max_size.is_none() || dir_size <= max_size.unwrap() {
+                    dirs.0.push(Directory(dir.to_string(), dir_size));
+                }
+            }
+
+            let base_size = fs.iter().map(|f| f.size).sum::<_>();
+            if max_size.is_none() || base_size <= max_size.unwrap() {
+                dirs.0.push(Directory("/".to_string(), base_size));
+            }
+
+            dirs
+        }
+
+        pub fn optimal_dir_to_delete(&self) -> Option<Directory> {
+            let dirs = self.ls_dirs(None).0;
+
+            let occupied_space = dirs.iter().find(|d| d.0 == "/").unwrap().1;
+            let free_space = TOTAL_SPACE - occupied_space;
+
+            if free_space >= MIN_FREE_SPACE_FOR_UPGRADE {
+                return None;
+            }
+
+            Some(
+                dirs.iter()
+                    .filter(|d| d.1 > MIN_FREE_SPACE_FOR_UPGRADE - free_space)
+                    .min_by(|a, b| a.1.cmp(&b.1))
+                    .unwrap()
+                    .clone(),
+            )
+        }
+    }
+
+    impl DirectoryCollection {
+        pub fn sum_sizes(&self) -> usize {
+            self.0.iter().map(|d| d.1).sum()
+        }
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Parsed = device::FileSystem;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: Input) -> Result<Self::Parsed> {
+        device::FileSystem::new(&input)
+    }
+
+    fn part1(&self, fs: &Self::Parsed) -> Result<usize> {
+        Ok(fs.ls_dirs(Some(100000)).sum_sizes())
+    }
+
+    fn part2(&self, fs: &Self::Parsed) -> Result<usize> {
+        match fs.optimal_dir_to_delete() {
+            Some(v) => Ok(v.1),
+            None => Err(Error::simulation(
+                "Solver::part2",
+                "There is already enough free space for the upgrade",
+            )),
+        }
+    }
+}
+
+pub fn aoc() -> AoC<usize, usize> {
+    AoC::new(YEAR, 7)
+}
+
+pub fn get_answer(input: Input) -> Result<Answer<usize, usize>> {
+    Solver.solve(input)
+}
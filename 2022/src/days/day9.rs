@@ -0,0 +1,247 @@
+use crate::{Answer, AoC, Input, Result, Solution, YEAR};
+
+pub mod rope_bridge {
+    use std::collections::HashSet;
+
+    use crate::{Error, Input, Result};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /* Assuming the following coordinate system:
+     * +y
+     *  ^
+     *  |
+     *  O--> +x
+     */
+    pub struct KnotPosition {
+        pub x: isize,
+        pub y: isize,
+    }
+
+    impl KnotPosition {
+        pub fn new(x: isize, y: isize) -> Self {
+            Self { x, y }
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct RopePosition(Vec<KnotPosition>);
+
+    impl std::fmt::Display for RopePosition {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let knots_positions = &self.0;
+            let knots_positions_len = knots_positions.len();
+
+            for (i, pos) in knots_positions.iter().enumerate() {
+                let name = if i == 0 {
+                    'H'
+                } else if i == knots_positions_len - 1 {
+                    'T'
+                } else {
+                    std::char::from_digit(i.try_into().unwrap(), 10).unwrap_or('*')
+                };
+                write!(f, "{}{{ {} {} }} ", name, pos.x, pos.y).unwrap();
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct RopePath(pub Vec<RopePosition>);
+
+    impl RopePath {
+        pub fn visited_tail_positions(&self) -> HashSet<KnotPosition> {
+            self.0.iter().map(|pos| pos.0[pos.0.len() - 1]).collect()
+        }
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    pub enum KnotStep {
+        Up,
+        Down,
+        Left,
+        Right,
+        UpLeft,
+        UpRight,
+        DownLeft,
+        DownRight,
+    }
+
+    #[derive(Debug, Clone)]
+    // A Rope is defined by a sequence of head steps and how many knots it has
+    pub struct Rope {
+        pub head_steps: Vec<KnotStep>,
+        pub knot_count: usize,
+    }
+
+    impl Rope {
+        pub fn new(input: &Input, knot_count: usize) -> Result<Self> {
+            if knot_count < 2 {
+                return Err(Error::parse(
+                    "Rope::new",
+                    "A rope must have at least 2 knots",
+                ));
+            }
+
+            let mut head_steps: Vec<KnotStep> = Vec::new();
+            let bad_input_msg = "Bad input";
+
+            for (i, line) in input.iter().enumerate() {
+                let parsed_line = line.split(' ').collect::<Vec<_>>();
+                if parsed_line.len() != 2 {
+                    return Err(input.error(
+                        "Rope::new",
+                        bad_input_msg,
+                        i,
+                        line,
+                        "a direction and a step count, like `R 4`",
+                    ));
+                }
+                let step: KnotStep = match parsed_line[0] {
+                    "U" => KnotStep::Up,
+                    "D" => KnotStep::Down,
+                    "L" => KnotStep::Left,
+                    "R" => KnotStep::Right,
+                    _ => {
+                        return Err(input.error(
+                            "Rope::new",
+                            bad_input_msg,
+                            i,
+                            parsed_line[0],
+                            "one of U, D, L or R",
+                        ))
+                    }
+                };
+                let count = match parsed_line[1].parse::<isize>() {
+                    Ok(v) => v,
+                    Err(e) => {
+                        return Err(input
+                            .error(
+                                "Rope::new",
+                                bad_input_msg,
+                                i,
+                                parsed_line[1],
+                                "a step count",
+                            )
+                            .with_source(e))
+                    }
+                };
+
+                for _ in 0..count {
+                    head_steps.push(step);
+                }
+            }
+
+            Ok(Rope {
+                head_steps,
+                knot_count,
+            })
+        }
+
+        fn get_new_pos(pos: &KnotPosition, step: &KnotStep) -> KnotPosition {
+            match step {
+                KnotStep::Up => KnotPosition::new(pos.x, pos.y + 1),
+                KnotStep::Down => KnotPosition::new(pos.x, pos.y - 1),
+                KnotStep::Left => KnotPosition::new(pos.x - 1, pos.y),
+                KnotStep::Right => KnotPosition::new(pos.x + 1, pos.y),
+                KnotStep::UpLeft => KnotPosition::new(pos.x - 1, pos.y + 1),
+                KnotStep::UpRight => KnotPosition::new(pos.x + 1, pos.y + 1),
+                KnotStep::DownLeft => KnotPosition::new(pos.x - 1, pos.y - 1),
+                KnotStep::DownRight => KnotPosition::new(pos.x + 1, pos.y - 1),
+            }
+        }
+
+        fn get_tail_pos(previous_tail_pos: &KnotPosition, head_pos: &KnotPosition) -> KnotPosition {
+            let distance_x = head_pos.x - previous_tail_pos.x;
+            let distance_y = head_pos.y - previous_tail_pos.y;
+
+            // Should move tail
+            if distance_x.abs() > 1 || distance_y.abs() > 1 {
+                let pos = previous_tail_pos;
+                fn norm(x: isize) -> i8 {
+                    if x == 0 {
+                        0
+                    } else {
+                        (x / x.abs()).try_into().unwrap()
+                    }
+                }
+                return match (norm(distance_x), norm(distance_y)) {
+                    (0, 1) => Self::get_new_pos(pos, &KnotStep::Up),
+                    (0, -1) => Self::get_new_pos(pos, &KnotStep::Down),
+                    (-1, 0) => Self::get_new_pos(pos, &KnotStep::Left),
+                    (1, 0) => Self::get_new_pos(pos, &KnotStep::Right),
+                    (-1, 1) => Self::get_new_pos(pos, &KnotStep::UpLeft),
+                    (1, 1) => Self::get_new_pos(pos, &KnotStep::UpRight),
+                    (-1, -1) => Self::get_new_pos(pos, &KnotStep::DownLeft),
+                    (1, -1) => Self::get_new_pos(pos, &KnotStep::DownRight),
+                    _ => {
+                        println!("{:?}", (distance_x, distance_y));
+                        panic!("Rope::get_tail_pos::Something has gone extremely wrong!")
+                    }
+                };
+            }
+
+            *previous_tail_pos
+        }
+
+        pub fn get_path(&self, origin: Option<isize>) -> Result<RopePath> {
+            let o = origin.unwrap_or(0);
+            let head_steps = &self.head_steps;
+            let mut path = vec![vec![KnotPosition::new(o, o); self.knot_count]];
+
+            for i in 0..head_steps.len() {
+                let previous_rope_pos = &path[i];
+                let mut new_rope_pos: Vec<KnotPosition> = Vec::new();
+
+                let head_pos = Self::get_new_pos(&previous_rope_pos[0], &head_steps[i]);
+                new_rope_pos.push(head_pos);
+
+                let mut relative_head_pos = head_pos;
+                for previous_knot_pos in previous_rope_pos.iter().skip(1) {
+                    let tail_pos = Self::get_tail_pos(previous_knot_pos, &relative_head_pos);
+                    new_rope_pos.push(tail_pos);
+
+                    relative_head_pos = tail_pos;
+                }
+
+                path.push(new_rope_pos);
+            }
+
+            Ok(RopePath(
+                path.iter().map(|p| RopePosition(p.to_vec())).collect(),
+            ))
+        }
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Parsed = rope_bridge::Rope;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: Input) -> Result<Self::Parsed> {
+        rope_bridge::Rope::new(&input, 2)
+    }
+
+    fn part1(&self, simple_rope: &Self::Parsed) -> Result<usize> {
+        Ok(simple_rope.get_path(None)?.visited_tail_positions().len())
+    }
+
+    fn part2(&self, simple_rope: &Self::Parsed) -> Result<usize> {
+        let complex_rope = rope_bridge::Rope {
+            knot_count: 10,
+            ..simple_rope.clone()
+        };
+
+        Ok(complex_rope.get_path(None)?.visited_tail_positions().len())
+    }
+}
+
+pub fn aoc() -> AoC<usize, usize> {
+    AoC::new(YEAR, 9)
+}
+
+pub fn get_answer(input: Input) -> Result<Answer<usize, usize>> {
+    Solver.solve(input)
+}
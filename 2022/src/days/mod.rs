@@ -0,0 +1,31 @@
+use crate::{Day, Registry};
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub fn registry() -> Registry {
+    Registry::new(vec![
+        Box::new(Day::new(day1::aoc(), day1::Solver)),
+        Box::new(Day::new(day2::aoc(), day2::Solver)),
+        Box::new(Day::new(day3::aoc(), day3::Solver)),
+        Box::new(Day::new(day4::aoc(), day4::Solver)),
+        Box::new(Day::new(day5::aoc(), day5::Solver)),
+        Box::new(Day::new(day6::aoc(), day6::Solver)),
+        Box::new(Day::new(day7::aoc(), day7::Solver)),
+        Box::new(Day::new(day8::aoc(), day8::Solver)),
+        Box::new(Day::new(day9::aoc(), day9::Solver)),
+        Box::new(Day::new(day10::aoc(), day10::Solver)),
+        Box::new(Day::new(day11::aoc(), day11::Solver)),
+        Box::new(Day::new(day12::aoc(), day12::Solver)),
+    ])
+}
@@ -0,0 +1,19 @@
+// Every example of every day, generated by build.rs from the fixture manifests
+fn check(day: u32, file: &str) {
+    let registry = aoc::days::registry();
+    let puzzle = match registry.get(day) {
+        Some(v) => v,
+        None => panic!("Day {} isn't in the registry", day),
+    };
+
+    match puzzle.test_fixture(file, &aoc::Options::default()) {
+        Ok(report) if !report.2.is_empty() => {
+            let mismatches: Vec<String> = report.2.iter().map(|m| m.to_string()).collect();
+            panic!("{}", mismatches.join("\n"));
+        }
+        Ok(_) => (),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/fixture_tests.rs"));
@@ -0,0 +1,139 @@
+// The days used as a library, the way other tools embed them
+use aoc::y2022::{day2, day4, day5, day6, day7, day9};
+use aoc::{Answer, Input, InputStream, Solution};
+
+fn example(content: &str) -> Input {
+    Input::from_content(content)
+}
+
+#[test]
+fn get_answer_solves_a_whole_input() {
+    let answer = day2::get_answer(example("A Y\nB X\nC Z\n")).unwrap();
+    assert_eq!(answer, Answer(15, 12));
+}
+
+#[test]
+fn elf_pair_overlaps() {
+    let pair = day4::elf_pair::ElfPair::new("2-8,3-7").unwrap();
+    assert!(pair.fully_contains());
+    assert!(pair.partially_contains());
+
+    let pair = day4::elf_pair::ElfPair::new("2-4,6-8").unwrap();
+    assert!(!pair.partially_contains());
+}
+
+#[test]
+fn supply_stacks_crane_models() {
+    let input = example(include_str!("../../data/2022/day5.test.txt"));
+    let drawing = day5::supply_stacks::DrawingComponents::new(&input).unwrap();
+    let stacks = day5::supply_stacks::SupplyStacks::new(drawing.stacks).unwrap();
+
+    let top_crates = |model| {
+        stacks
+            .clone()
+            .operate_crane(&drawing.instructions, model)
+            .unwrap()
+            .list_top_crates()
+    };
+    assert_eq!(top_crates(9000), "CMZ");
+    assert_eq!(top_crates(9001), "MCD");
+}
+
+#[test]
+fn supply_stacks_with_trimmed_drawing_lines() {
+    let drawing = include_str!("../../data/2022/day5.test.txt")
+        .lines()
+        .map(|line| format!("{}\n", line.trim_end()))
+        .collect::<String>();
+    let answer = day5::get_answer(example(&drawing)).unwrap();
+    assert_eq!(answer, Answer("CMZ".to_string(), "MCD".to_string()));
+}
+
+#[test]
+fn file_system_directory_sizes() {
+    let input = example(include_str!("../../data/2022/day7.test.txt"));
+    let fs = day7::device::FileSystem::new(&input).unwrap();
+
+    assert_eq!(fs.ls_dirs(Some(100000)).sum_sizes(), 95437);
+    assert_eq!(fs.optimal_dir_to_delete().unwrap().1, 24933642);
+}
+
+#[test]
+fn rope_tail_follows_the_head() {
+    let input = example(include_str!("../../data/2022/day9.test.txt"));
+    let rope = day9::rope_bridge::Rope::new(&input, 2).unwrap();
+    let path = rope.get_path(None).unwrap();
+
+    assert_eq!(path.0.len(), rope.head_steps.len() + 1);
+    assert_eq!(path.visited_tail_positions().len(), 13);
+}
+
+// Read the way `aoc` reads a real input, with the day's normalization
+fn signal(content: &str) -> (aoc::Result<day6::communication_system::Signal>, InputStream) {
+    let cursor = std::io::Cursor::new(content.to_string());
+    let mut input = InputStream::new("signal", cursor)
+        .normalized(day6::Solver::NORMALIZATION)
+        .unwrap();
+    (day6::Solver.parse_stream(&mut input), input)
+}
+
+#[test]
+fn signal_line_ends_are_normalized() {
+    for (content, cr_lines, trimmed_lines) in [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb \n", 0, 1),
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb\r\n", 1, 0),
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb \t\r", 1, 1),
+    ] {
+        let (parsed, input) = signal(content);
+        let signal = parsed.unwrap();
+        let part1 = day6::Solver.part1(&signal).unwrap();
+        assert_eq!((part1, day6::Solver.part2(&signal).unwrap()), (7, 19));
+        let report = input.report();
+        assert_eq!(
+            (report.cr_lines, report.trimmed_lines),
+            (cr_lines, trimmed_lines)
+        );
+    }
+}
+
+#[test]
+fn signal_errors_point_at_the_column_in_the_whole_line() {
+    let (parsed, _) = signal("mjqjpqmgbljsphdztnvjfqwrcgsm lb\n");
+    let error = parsed.unwrap_err();
+    let expected = [
+        "Signal::new::Bad input",
+        " --> line 1, column 29",
+        "  |",
+        "1 | …dztnvjfqwrcgsm ",
+        "  |                ^ expected a lowercase letter, found ` `",
+    ];
+    assert_eq!(error.to_string(), expected.join("\n"));
+}
+
+#[test]
+fn signal_without_a_marker_is_an_error() {
+    for signal in ["abc\n", "abcabcabcabc\n"] {
+        let error = day6::get_answer(example(signal)).unwrap_err();
+        assert!(error.to_string().contains("Bad signal"), "{}", error);
+    }
+}
+
+#[test]
+fn parts_without_expected_answers_are_still_solved() {
+    let data_dir = std::env::temp_dir().join(format!("aoc-unchecked-{}", std::process::id()));
+    let year_dir = data_dir.join("2022");
+    std::fs::create_dir_all(&year_dir).unwrap();
+    std::fs::write(year_dir.join("day1.input.txt"), "1\n\n2\n\n3\n").unwrap();
+    std::fs::write(year_dir.join("day1.fixtures.txt"), "").unwrap();
+
+    let options = aoc::Options {
+        format: aoc::Format::Json,
+        data_dir: Some(data_dir.clone()),
+        ..aoc::Options::default()
+    };
+    let results = aoc::days::registry().get(1).unwrap().run(&options).unwrap();
+    std::fs::remove_dir_all(data_dir).unwrap();
+
+    let statuses: Vec<_> = results.iter().map(|r| r.status).collect();
+    assert_eq!(statuses, [aoc::Status::Unchecked, aoc::Status::Unchecked]);
+}
@@ -0,0 +1,307 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::history::{append_history, load_history};
+use crate::{workspace_dir, Bench, Input, Result, Stage, Stats, Time};
+
+// Each year keeps its own history, `benchmarks/2022.txt` in the workspace
+pub fn baselines_file(year: u32) -> PathBuf {
+    workspace_dir()
+        .join("benchmarks")
+        .join(format!("{}.txt", year))
+}
+
+// Changes smaller than this are noise from the machine, not the solver
+const NOISE_FLOOR: Duration = Duration::from_micros(50);
+
+const HEADER: &str =
+    "# revision\tunix_time\tday\tstage\truns\tmin_ns\tmedian_ns\tmean_ns\tstddev_ns";
+
+// One stage of one day as it was benchmarked at some revision
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub revision: String,
+    pub unix_time: u64,
+    pub day: u32,
+    pub stage: Stage,
+    pub runs: usize,
+    pub stats: Stats,
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.revision,
+            self.unix_time,
+            self.day,
+            self.stage,
+            self.runs,
+            self.stats.min.as_nanos(),
+            self.stats.median.as_nanos(),
+            self.stats.mean.as_nanos(),
+            self.stats.stddev.as_nanos()
+        )
+    }
+}
+
+impl Record {
+    // Reads back what the Display impl wrote, see load_history
+    fn new(file: &Input, i: usize) -> Result<Self> {
+        let context = "Record::new";
+        let line = &file[i];
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields.len() != 9 {
+            return Err(file.error(context, "Bad record", i, line, "9 tab separated fields"));
+        }
+
+        let number = |field: &str| {
+            field.parse::<u64>().map_err(|e| {
+                file.error(context, "Bad record", i, field, "a number")
+                    .with_source(e)
+            })
+        };
+        let nanos = |field: &str| number(field).map(Duration::from_nanos);
+
+        Ok(Self {
+            revision: fields[0].to_string(),
+            unix_time: number(fields[1])?,
+            day: number(fields[2])? as u32,
+            stage: fields[3]
+                .parse()
+                .map_err(|e| file.error(context, e, i, fields[3], "a stage"))?,
+            runs: number(fields[4])? as usize,
+            stats: Stats {
+                min: nanos(fields[5])?,
+                median: nanos(fields[6])?,
+                mean: nanos(fields[7])?,
+                stddev: nanos(fields[8])?,
+            },
+        })
+    }
+}
+
+// The benchmark history of a year, oldest first
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baselines {
+    pub path: PathBuf,
+    pub records: Vec<Record>,
+}
+
+impl Baselines {
+    // A missing file is just an empty history
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let records = load_history(&path, Record::new)?;
+        Ok(Self { path, records })
+    }
+
+    // Appends a record per stage of every bench, tagged with `revision`
+    pub fn save(&mut self, benches: &[Bench], revision: &str) -> Result<()> {
+        let unix_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let new_records: Vec<Record> = benches
+            .iter()
+            .flat_map(|bench| {
+                Stage::VALUES.into_iter().map(move |stage| Record {
+                    revision: revision.to_string(),
+                    unix_time,
+                    day: bench.day,
+                    stage,
+                    runs: bench.runs.len(),
+                    stats: bench.stats(stage),
+                })
+            })
+            .collect();
+
+        append_history(&self.path, HEADER, &new_records)?;
+        self.records.extend(new_records);
+        Ok(())
+    }
+
+    // The newest record for a day and stage, optionally only from `revision`
+    pub fn latest(&self, day: u32, stage: Stage, revision: Option<&str>) -> Option<&Record> {
+        self.records.iter().rev().find(|r| {
+            r.day == day && r.stage == stage && revision.is_none_or(|rev| r.revision == rev)
+        })
+    }
+
+    // Compares every stage of `bench` against its newest baseline
+    pub fn compare(&self, bench: &Bench, revision: Option<&str>) -> Vec<Comparison> {
+        Stage::VALUES
+            .into_iter()
+            .filter_map(|stage| {
+                self.latest(bench.day, stage, revision)
+                    .map(|baseline| Comparison {
+                        day: bench.day,
+                        stage,
+                        revision: baseline.revision.clone(),
+                        baseline: baseline.stats.median,
+                        current: bench.stats(stage).median,
+                    })
+            })
+            .collect()
+    }
+}
+
+// How the median of a stage moved since its baseline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub day: u32,
+    pub stage: Stage,
+    pub revision: String,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    pub fn change_percent(&self) -> f64 {
+        if self.baseline.is_zero() {
+            return 0.0;
+        }
+        (self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1.0) * 100.0
+    }
+
+    pub fn regressed(&self, threshold_percent: f64) -> bool {
+        self.current > self.baseline + NOISE_FLOOR && self.change_percent() > threshold_percent
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "  {:<6} {:<6} {:>10} {:>10} {:>+8.1}%  (baseline {})",
+            self.day,
+            self.stage,
+            Time(self.baseline),
+            Time(self.current),
+            self.change_percent(),
+            self.revision
+        )
+    }
+}
+
+// Short hash of the workspace's HEAD, wherever `aoc` is run from, with
+// `-dirty` when there are uncommitted changes. `unknown` outside of a git
+// checkout
+pub fn git_revision() -> String {
+    let git = |args: &[&str]| {
+        std::process::Command::new("git")
+            .arg("-C")
+            .arg(workspace_dir())
+            .args(args)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain"]) {
+            Some(status) if !status.is_empty() => format!("{}-dirty", hash),
+            _ => hash,
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Timings;
+
+    fn record(revision: &str, day: u32, stage: Stage, median_ms: u64) -> Record {
+        let median = Duration::from_millis(median_ms);
+        Record {
+            revision: revision.to_string(),
+            unix_time: 0,
+            day,
+            stage,
+            runs: 1,
+            stats: Stats {
+                min: median,
+                median,
+                mean: median,
+                stddev: Duration::ZERO,
+            },
+        }
+    }
+
+    fn comparison(baseline: Duration, current: Duration) -> Comparison {
+        Comparison {
+            day: 1,
+            stage: Stage::Total,
+            revision: "abc1234".to_string(),
+            baseline,
+            current,
+        }
+    }
+
+    #[test]
+    fn regressed_past_the_threshold() {
+        let ms = Duration::from_millis;
+        assert!(comparison(ms(10), ms(12)).regressed(10.0));
+        assert!(!comparison(ms(10), ms(11)).regressed(10.0));
+        assert!(!comparison(ms(10), ms(5)).regressed(0.0));
+        assert_eq!(comparison(ms(10), ms(5)).change_percent(), -50.0);
+    }
+
+    #[test]
+    fn changes_under_the_noise_floor_never_regress() {
+        let us = Duration::from_micros;
+        // 4x slower, but only by 30µs
+        assert!(!comparison(us(10), us(40)).regressed(10.0));
+        assert_eq!(comparison(Duration::ZERO, us(40)).change_percent(), 0.0);
+    }
+
+    #[test]
+    fn compares_against_the_newest_baseline() {
+        let baselines = Baselines {
+            path: PathBuf::new(),
+            records: vec![
+                record("old", 1, Stage::Part1, 10),
+                record("new", 1, Stage::Part1, 20),
+                record("new", 2, Stage::Part1, 99),
+            ],
+        };
+        let bench = Bench {
+            day: 1,
+            runs: vec![Timings {
+                part_1: Duration::from_millis(30),
+                ..Timings::default()
+            }],
+        };
+
+        let comparisons = baselines.compare(&bench, None);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].revision, "new");
+        assert_eq!(comparisons[0].baseline, Duration::from_millis(20));
+        assert_eq!(comparisons[0].current, Duration::from_millis(30));
+
+        let comparisons = baselines.compare(&bench, Some("old"));
+        assert_eq!(comparisons[0].baseline, Duration::from_millis(10));
+        assert!(baselines.compare(&bench, Some("missing")).is_empty());
+    }
+
+    #[test]
+    fn saved_records_load_back() {
+        let path = std::env::temp_dir().join(format!("aoc-baselines-{}.txt", std::process::id()));
+        let bench = Bench {
+            day: 3,
+            runs: vec![Timings::default()],
+        };
+        let mut baselines = Baselines::load(&path).unwrap();
+        assert!(baselines.records.is_empty());
+        baselines.save(&[bench], "abc1234").unwrap();
+
+        let loaded = Baselines::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.records, baselines.records);
+        assert_eq!(loaded.records.len(), Stage::VALUES.len());
+    }
+}
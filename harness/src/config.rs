@@ -0,0 +1,129 @@
+use std::path::PathBuf;
+
+use crate::{Error, Input, Result};
+
+// Where the config file is read from instead of the user's config directory
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+// Override the config file's `session` and `base_url`
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// Settings for talking to adventofcode.com. Kept out of the repository since
+// the session token is as good as a password
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    // The `session` cookie of a logged in browser
+    pub session: Option<String>,
+    // Where requests go, a local stub server when testing
+    pub base_url: Option<String>,
+    // An email or URL added to the User-Agent so the site can get in touch
+    pub contact: Option<String>,
+    // The id of the private leaderboard `aoc leaderboard` downloads
+    pub leaderboard: Option<String>,
+    // Where inputs are kept instead of the default vault, see vault_dir
+    pub vault: Option<String>,
+}
+
+// $AOC_CONFIG, or `aoc/config` in $XDG_CONFIG_HOME or ~/.config
+pub fn config_file() -> Option<PathBuf> {
+    let var = |name| std::env::var_os(name).filter(|v| !v.is_empty());
+    if let Some(file) = var(CONFIG_VAR) {
+        return Some(PathBuf::from(file));
+    }
+    let config_dir = match var("XDG_CONFIG_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(var("HOME")?).join(".config"),
+    };
+    Some(config_dir.join("aoc").join("config"))
+}
+
+impl Config {
+    // Reads the config file if there is one, then the environment variables
+    pub fn load() -> Result<Self> {
+        let mut config = match config_file() {
+            Some(file) if file.is_file() => Self::parse(&Input::read(&file)?)?,
+            _ => Self::default(),
+        };
+
+        let var = |name| std::env::var(name).ok().filter(|v| !v.is_empty());
+        if let Some(session) = var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+        if let Some(base_url) = var(BASE_URL_VAR) {
+            config.base_url = Some(base_url);
+        }
+        Ok(config)
+    }
+
+    // Parses `key = value` lines, with comments starting with #:
+    //
+    //     session = 53616c7465645f5f...
+    //     contact = me@example.com
+    //     leaderboard = 123456
+    //
+    // Errors only give the line number and key, a line can hold the token
+    pub fn parse(input: &Input) -> Result<Self> {
+        let mut config = Self::default();
+
+        for (i, line) in input.iter().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(Error::Config {
+                    message: format!("Line {} of the config isn't `<key> = <value>`", i + 1),
+                });
+            };
+            let (key, value) = (key.trim(), value.trim().to_string());
+            match key {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = Some(value),
+                "contact" => config.contact = Some(value),
+                "leaderboard" => config.leaderboard = Some(value),
+                "vault" => config.vault = Some(value),
+                _ => {
+                    return Err(Error::Config {
+                        message: format!(
+                            "Unknown key `{}` on line {} of the config, expected `session`, `base_url`, `contact`, `leaderboard` or `vault`",
+                            key,
+                            i + 1
+                        ),
+                    })
+                }
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url
+            .as_deref()
+            .unwrap_or(DEFAULT_BASE_URL)
+            .trim_end_matches('/')
+    }
+
+    pub fn session(&self) -> Result<&str> {
+        self.session.as_deref().ok_or_else(|| Error::Config {
+            message: format!(
+                "No session token. Set {} or add `session = <token>` to {}",
+                SESSION_VAR,
+                config_file().map_or("the config file".to_string(), |f| f.display().to_string())
+            ),
+        })
+    }
+
+    // Identifies the harness to the server, as adventofcode.com asks of
+    // automated tools
+    pub fn user_agent(&self) -> String {
+        let agent = concat!("aoc_harness/", env!("CARGO_PKG_VERSION"));
+        match &self.contact {
+            Some(contact) => format!("{} ({})", agent, contact),
+            None => agent.to_string(),
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::SystemTime;
+
+use crate::{to_hex, Config, Error, Result};
+
+// Overrides where data files are read from, like `--data-dir` does
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+// Overrides where the input vault is
+pub const VAULT_VAR: &str = "AOC_VAULT";
+// The secret answer_hash is keyed with, one per year next to the inputs
+pub const ANSWER_KEY_FILE: &str = "answers.key";
+// The answer key itself, for where the inputs aren't, like CI
+pub const ANSWER_KEY_VAR: &str = "AOC_ANSWER_KEY";
+
+// The root of the workspace this harness was built in
+pub fn workspace_dir() -> PathBuf {
+    let harness_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    harness_dir.parent().unwrap_or(harness_dir).to_path_buf()
+}
+
+// Where data files of `year` are looked for, in order. A data directory has
+// a directory per year, like the workspace's `data/2022`. An explicit
+// directory or $AOC_DATA_DIR is the only place looked in. Otherwise it's the
+// workspace's `data`, wherever the binary is run from, and then `./data` for
+// binaries that were moved away from the workspace
+pub fn data_dirs(year: u32, data_dir: Option<&Path>) -> Vec<PathBuf> {
+    let year = year.to_string();
+    if let Some(dir) = explicit_data_dir(data_dir) {
+        return vec![dir.join(year)];
+    }
+
+    let workspace_dir = workspace_dir().join("data").join(&year);
+    let cwd_dir = PathBuf::from("./data").join(&year);
+    let same_dir = match (workspace_dir.canonicalize(), cwd_dir.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    };
+    if same_dir {
+        vec![workspace_dir]
+    } else {
+        vec![workspace_dir, cwd_dir]
+    }
+}
+
+fn explicit_data_dir(data_dir: Option<&Path>) -> Option<PathBuf> {
+    match data_dir {
+        Some(dir) => Some(dir.to_path_buf()),
+        None => std::env::var_os(DATA_DIR_VAR)
+            .filter(|d| !d.is_empty())
+            .map(PathBuf::from),
+    }
+}
+
+// The config's `vault`, read once. A config that doesn't parse is warned
+// about and left out, finding inputs doesn't need the rest of it
+fn config_vault() -> Option<PathBuf> {
+    static VAULT: OnceLock<Option<PathBuf>> = OnceLock::new();
+    VAULT
+        .get_or_init(|| match Config::load() {
+            Ok(config) => config.vault.map(PathBuf::from),
+            Err(e) => {
+                eprintln!("Warning: inputs are looked for without the config: {}", e);
+                None
+            }
+        })
+        .clone()
+}
+
+// Where puzzle inputs are kept out of the repository, since they aren't ours
+// to publish: $AOC_VAULT, the config's `vault`, or `aoc/inputs` in
+// $XDG_DATA_HOME or ~/.local/share. Like a data directory it has a directory
+// per year
+pub fn vault_dir() -> Option<PathBuf> {
+    let var = |name| std::env::var_os(name).filter(|v| !v.is_empty());
+    if let Some(dir) = var(VAULT_VAR) {
+        return Some(PathBuf::from(dir));
+    }
+    if let Some(dir) = config_vault() {
+        return Some(dir);
+    }
+    let data_home = match (var("XDG_DATA_HOME"), var("HOME")) {
+        (Some(dir), _) => PathBuf::from(dir),
+        (None, Some(home)) => PathBuf::from(home).join(".local").join("share"),
+        (None, None) => return None,
+    };
+    Some(data_home.join("aoc").join("inputs"))
+}
+
+// Where inputs of `year` are looked for, in order: the vault and then the data
+// directories. An explicit data directory is the only place looked in, so the
+// vault doesn't get in the way of testing with other data
+pub fn input_dirs(year: u32, data_dir: Option<&Path>) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if explicit_data_dir(data_dir).is_none() {
+        dirs.extend(vault_dir().map(|dir| dir.join(year.to_string())));
+    }
+    dirs.extend(data_dirs(year, data_dir));
+    dirs
+}
+
+// The first input directory of `year` that has `file`
+pub fn find_input_file(file: &str, year: u32, data_dir: Option<&Path>) -> Result<PathBuf> {
+    find_in(file, input_dirs(year, data_dir))
+}
+
+// The first data directory of `year` that has `file`
+pub fn find_data_file(file: &str, year: u32, data_dir: Option<&Path>) -> Result<PathBuf> {
+    find_in(file, data_dirs(year, data_dir))
+}
+
+// The key of `year`'s answer hashes, from $AOC_ANSWER_KEY or else the key
+// file. It's as private as the inputs, so the file is looked for where they
+// are, and when there's none yet and `create` it's made in the first input
+// directory, the vault unless there's an explicit data directory. A year that
+// already has accepted answers doesn't get a new one, they couldn't be
+// checked with it. The key is the text of the file, so tools that take the
+// key as an argument can use it as is
+pub fn answer_key(year: u32, data_dir: Option<&Path>, create: bool) -> Result<Vec<u8>> {
+    if let Ok(key) = std::env::var(ANSWER_KEY_VAR) {
+        if !key.trim().is_empty() {
+            return Ok(key.trim().as_bytes().to_vec());
+        }
+    }
+    let path = match find_input_file(ANSWER_KEY_FILE, year, data_dir) {
+        Ok(path) => path,
+        Err(_) if !create || has_accepted_answers(year, data_dir) => {
+            return Err(Error::Config {
+                message: format!(
+                    "There's no {} for {}'s answers. Put it next to the inputs, or set {} to what's in it",
+                    ANSWER_KEY_FILE, year, ANSWER_KEY_VAR
+                ),
+            })
+        }
+        Err(_) => {
+            let key = new_key();
+            let dir = input_dirs(year, data_dir).remove(0);
+            let path = dir.join(ANSWER_KEY_FILE);
+            std::fs::create_dir_all(&dir)
+                .and_then(|()| std::fs::write(&path, format!("{}\n", key)))
+                .map_err(|source| Error::Io { path, source })?;
+            return Ok(key.into_bytes());
+        }
+    };
+    match std::fs::read_to_string(&path) {
+        Ok(key) if !key.trim().is_empty() => Ok(key.trim().as_bytes().to_vec()),
+        Ok(_) => Err(Error::Config {
+            message: format!("{} is empty", path.display()),
+        }),
+        Err(source) => Err(Error::Io { path, source }),
+    }
+}
+
+fn has_accepted_answers(year: u32, data_dir: Option<&Path>) -> bool {
+    data_dirs(year, data_dir)
+        .iter()
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .any(|name| name.starts_with("day") && name.ends_with(".answers.txt"))
+}
+
+// 256 random bits as hex. std has no random numbers of its own, but every
+// RandomState is seeded from the system's randomness
+fn new_key() -> String {
+    let time = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let words: Vec<u8> = (0..4u64)
+        .flat_map(|i| {
+            let mut hasher = RandomState::new().build_hasher();
+            hasher.write_u64(i);
+            hasher.write_u128(time);
+            hasher.finish().to_be_bytes()
+        })
+        .collect();
+    to_hex(&words)
+}
+
+fn find_in(file: &str, dirs: Vec<PathBuf>) -> Result<PathBuf> {
+    let tried: Vec<PathBuf> = dirs.into_iter().map(|dir| dir.join(file)).collect();
+
+    match tried.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(Error::NotFound {
+            file: file.to_string(),
+            tried,
+        }),
+    }
+}
+
+// Moves every `dayN.input.txt` and answer key of the data directory into the
+// vault, keeping the year directories, so only the examples stay in the
+// repository. Nothing is moved when an input with different content is
+// already in the vault. Returns where each input was and where it went
+pub fn migrate_inputs(data_dir: Option<&Path>) -> Result<Vec<(PathBuf, PathBuf)>> {
+    // Moving inputs somewhere a broken config didn't mean would be worse
+    // than not moving them
+    Config::load()?;
+    let Some(vault) = vault_dir() else {
+        return Err(Error::Config {
+            message: format!("There's no vault directory, set {}", VAULT_VAR),
+        });
+    };
+    let data_dir = explicit_data_dir(data_dir).unwrap_or_else(|| workspace_dir().join("data"));
+    let io_error = |path: &Path| {
+        let path = path.to_path_buf();
+        move |source| Error::Io { path, source }
+    };
+    let is_input = |name: &str| {
+        name == ANSWER_KEY_FILE
+            || name
+                .strip_prefix("day")
+                .and_then(|n| n.strip_suffix(".input.txt"))
+                .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+    };
+
+    let mut moves = Vec::new();
+    for year in std::fs::read_dir(&data_dir).map_err(io_error(&data_dir))? {
+        let year = year.map_err(io_error(&data_dir))?.path();
+        let Some(year_name) = year.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if !year.is_dir() || year_name.parse::<u32>().is_err() {
+            continue;
+        }
+        for file in std::fs::read_dir(&year).map_err(io_error(&year))? {
+            let file = file.map_err(io_error(&year))?.path();
+            if file
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(is_input)
+            {
+                let to = vault
+                    .join(year_name)
+                    .join(file.file_name().unwrap_or_default());
+                moves.push((file, to));
+            }
+        }
+    }
+    moves.sort();
+
+    let read = |path: &Path| std::fs::read(path).map_err(io_error(path));
+    let mut conflicts = Vec::new();
+    for (from, to) in &moves {
+        if to.exists() && read(from)? != read(to)? {
+            conflicts.push(to.clone());
+        }
+    }
+    if !conflicts.is_empty() {
+        return Err(Error::Exists { paths: conflicts });
+    }
+
+    for (from, to) in &moves {
+        if to.exists() {
+            std::fs::remove_file(from).map_err(io_error(from))?;
+            continue;
+        }
+        if let Some(dir) = to.parent() {
+            std::fs::create_dir_all(dir).map_err(io_error(dir))?;
+        }
+        // A rename can't cross file systems, a copy can
+        if std::fs::rename(from, to).is_err() {
+            std::fs::copy(from, to).map_err(io_error(to))?;
+            std::fs::remove_file(from).map_err(io_error(from))?;
+        }
+    }
+    Ok(moves)
+}
@@ -0,0 +1,371 @@
+use std::fmt::Display;
+use std::path::PathBuf;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+type Source = Box<dyn std::error::Error + Send + Sync>;
+
+#[derive(Debug)]
+pub enum Error {
+    // Reading an input file failed
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    // A data file isn't in any of the places it was looked for
+    NotFound {
+        file: String,
+        tried: Vec<PathBuf>,
+    },
+    // Files that would have been overwritten
+    Exists {
+        paths: Vec<PathBuf>,
+    },
+    // The input doesn't have the shape a day expects. `context` follows the
+    // `Type::method` naming used across the days, e.g. `Rope::new`
+    Parse {
+        context: &'static str,
+        message: String,
+        location: Option<Box<Location>>,
+        source: Option<Source>,
+    },
+    // A day computed something other than the expected example answer for
+    // these parts. What it got wrong was reported as a Mismatch
+    TestMismatch {
+        day: u32,
+        parts: Vec<u8>,
+    },
+    // The input parsed fine but the puzzle can't be solved with it, e.g. a
+    // crane trying to move crates out of an empty stack
+    Simulation {
+        context: &'static str,
+        message: String,
+    },
+    // A request to adventofcode.com, or whatever the config points at,
+    // didn't get through or was turned down
+    Http {
+        url: String,
+        message: String,
+    },
+    // A setting a command needs is missing
+    Config {
+        message: String,
+    },
+    // A command was given something it can't work with, e.g. day 26
+    Argument {
+        message: String,
+    },
+    // An answer wasn't submitted since earlier submissions already rule it
+    // out, or the site asked to wait
+    Refused {
+        message: String,
+    },
+}
+
+// Where in the input a parse error happened. Lines and columns are 1-based
+// and columns count chars, not bytes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: Option<usize>,
+    pub column: usize,
+    pub len: usize,
+    pub text: String,
+    // How many chars of the line come before `text`, when only its end was
+    // kept
+    pub cut: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl Location {
+    // `token` should be a slice of `line`, as returned by `split` and
+    // friends. If it isn't, it is searched for in the line instead
+    pub fn new(line: &str, token: &str, expected: impl Display) -> Self {
+        let line_start = line.as_ptr() as usize;
+        let token_start = token.as_ptr() as usize;
+        let offset =
+            if token_start >= line_start && token_start + token.len() <= line_start + line.len() {
+                token_start - line_start
+            } else if token.is_empty() {
+                line.len()
+            } else {
+                line.find(token).unwrap_or(line.len())
+            };
+
+        Self {
+            line: None,
+            column: line[..offset].chars().count() + 1,
+            len: token.chars().count().max(1),
+            text: line.to_string(),
+            cut: 0,
+            expected: expected.to_string(),
+            found: if token.is_empty() {
+                "end of line".to_string()
+            } else {
+                format!("`{}`", token)
+            },
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line_label = self.line.map(|l| l.to_string()).unwrap_or_default();
+        let gutter = " ".repeat(line_label.len());
+
+        match self.line {
+            Some(line) => writeln!(f, "{}--> line {}, column {}", gutter, line, self.column)?,
+            None => writeln!(f, "{}--> column {}", gutter, self.column)?,
+        }
+        writeln!(f, "{} |", gutter)?;
+        let ellipsis = if self.cut > 0 { "…" } else { "" };
+        writeln!(f, "{} | {}{}", line_label, ellipsis, self.text)?;
+        write!(
+            f,
+            "{} | {}{} expected {}, found {}",
+            gutter,
+            " ".repeat(self.column - 1 - self.cut + ellipsis.chars().count()),
+            "^".repeat(self.len),
+            self.expected,
+            self.found
+        )
+    }
+}
+
+impl Error {
+    pub fn parse(context: &'static str, message: impl Display) -> Self {
+        Self::Parse {
+            context,
+            message: message.to_string(),
+            location: None,
+            source: None,
+        }
+    }
+
+    pub fn simulation(context: &'static str, message: impl Display) -> Self {
+        Self::Simulation {
+            context,
+            message: message.to_string(),
+        }
+    }
+
+    // Attaches the underlying error to a parse error, e.g. a ParseIntError
+    pub fn with_source(mut self, error: impl std::error::Error + Send + Sync + 'static) -> Self {
+        if let Self::Parse { source, .. } = &mut self {
+            *source = Some(Box::new(error));
+        }
+        self
+    }
+
+    // Points a parse error at `token` inside `line`. Parsers that only see a
+    // single line use this and let the caller add the line number with
+    // `on_line`
+    pub fn found(mut self, line: &str, token: &str, expected: impl Display) -> Self {
+        if let Self::Parse { location, .. } = &mut self {
+            *location = Some(Box::new(Location::new(line, token, expected)));
+        }
+        self
+    }
+
+    // Sets the 0-based index of the input line a located parse error is on
+    pub fn on_line(mut self, index: usize) -> Self {
+        if let Self::Parse {
+            location: Some(location),
+            ..
+        } = &mut self
+        {
+            location.line = Some(index + 1);
+        }
+        self
+    }
+
+    // Moves a located parse error down by `offset` lines, for parsers that
+    // only got handed a slice of the input
+    pub fn shift_lines(mut self, offset: usize) -> Self {
+        if let Self::Parse {
+            location: Some(location),
+            ..
+        } = &mut self
+        {
+            location.line = location.line.map(|l| l + offset);
+        }
+        self
+    }
+
+    // Moves a located parse error right by `offset` columns, for parsers
+    // that only kept the end of a long line and located the error in that
+    pub fn shift_columns(mut self, offset: usize) -> Self {
+        if let Self::Parse {
+            location: Some(location),
+            ..
+        } = &mut self
+        {
+            location.column += offset;
+            location.cut += offset;
+        }
+        self
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            Self::Parse { location, .. } => location.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, .. } => write!(f, "Could not read {}", path.display()),
+            Self::NotFound { file, tried } => {
+                write!(f, "Could not find {}. Tried:", file)?;
+                for path in tried {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            Self::Exists { paths } => {
+                write!(f, "Refusing to overwrite existing files:")?;
+                for path in paths {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            Self::Parse {
+                context,
+                message,
+                location,
+                ..
+            } => {
+                write!(f, "{}::{}", context, message)?;
+                if let Some(location) = location {
+                    write!(f, "\n{}", location)?;
+                }
+                Ok(())
+            }
+            Self::TestMismatch { day, parts } => {
+                let parts: Vec<String> = parts.iter().map(|p| p.to_string()).collect();
+                write!(
+                    f,
+                    "Day {} got examples of part {} wrong. You haven't got it yet 😉",
+                    day,
+                    parts.join(" and ")
+                )
+            }
+            Self::Simulation { context, message } => write!(f, "{}::{}", context, message),
+            Self::Http { url, message } => write!(f, "Request to {} failed: {}", url, message),
+            Self::Config { message } | Self::Argument { message } | Self::Refused { message } => {
+                write!(f, "{}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::Parse {
+                source: Some(source),
+                ..
+            } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+// Prints the error with its chain of sources and turns it into an exit code,
+// so binaries can end with `aoc::report(...)` instead of panicking
+pub fn report(result: Result<()>) -> std::process::ExitCode {
+    match result {
+        Ok(()) => std::process::ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            let mut source = std::error::Error::source(&e);
+            while let Some(s) = source {
+                eprintln!("  Caused by: {}", s);
+                source = s.source();
+            }
+            std::process::ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caret_points_at_a_slice_of_the_line() {
+        let line = "R 4 x";
+        let token = line.split(' ').nth(2).unwrap();
+        let error = Error::parse("Rope::new", "Bad step")
+            .found(line, token, "a number")
+            .on_line(2);
+
+        let expected = [
+            "Rope::new::Bad step",
+            " --> line 3, column 5",
+            "  |",
+            "3 | R 4 x",
+            "  |     ^ expected a number, found `x`",
+        ];
+        assert_eq!(error.to_string(), expected.join("\n"));
+    }
+
+    #[test]
+    fn shifted_columns_mark_the_cut_line() {
+        let error = Error::parse("Signal::new", "Bad input")
+            .found("abc1", "1", "a letter")
+            .on_line(0)
+            .shift_columns(20);
+
+        let expected = [
+            "Signal::new::Bad input",
+            " --> line 1, column 24",
+            "  |",
+            "1 | …abc1",
+            "  |     ^ expected a letter, found `1`",
+        ];
+        assert_eq!(error.to_string(), expected.join("\n"));
+    }
+
+    #[test]
+    fn columns_count_chars_and_carets_cover_the_token() {
+        let location = Location::new("é 12ab", "12ab", "digits");
+        assert_eq!(location.column, 3);
+        assert_eq!(location.len, 4);
+        assert!(location
+            .to_string()
+            .ends_with("|   ^^^^ expected digits, found `12ab`"));
+    }
+
+    #[test]
+    fn token_outside_the_line_is_searched_for() {
+        let owned = "B".to_string();
+        let location = Location::new("A B C", &owned, "a move");
+        assert_eq!(location.column, 3);
+    }
+
+    #[test]
+    fn empty_token_points_past_the_end() {
+        let line = "A ";
+        let location = Location::new(line, &line[2..], "a move");
+        assert_eq!((location.column, location.len), (3, 1));
+        assert_eq!(location.found, "end of line");
+        assert!(location.to_string().starts_with("--> column 3\n"));
+    }
+
+    #[test]
+    fn shift_lines_moves_located_errors_only() {
+        let located = Error::parse("Stacks::new", "Bad crate")
+            .found("[A]", "[A]", "a crate")
+            .on_line(0)
+            .shift_lines(4);
+        assert_eq!(located.location().unwrap().line, Some(5));
+
+        let unlocated = Error::parse("Stacks::new", "Bad crate").shift_lines(4);
+        assert!(unlocated.location().is_none());
+    }
+}
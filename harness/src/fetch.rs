@@ -0,0 +1,73 @@
+use std::path::{Path, PathBuf};
+
+use crate::{find_input_file, input_dirs, Config, Error, Request, Result};
+
+// What `fetch_input` did
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    // The input was already there, so the server wasn't asked
+    Cached(PathBuf),
+}
+
+// Downloads the personal input of a day into the first input directory of
+// `year`, the vault unless there's an explicit data directory. An input
+// that's already in any of them is only downloaded again when `force`d. An
+// empty one, like older `aoc new`s left behind, doesn't count
+pub fn fetch_input(
+    config: &Config,
+    year: u32,
+    day: u32,
+    data_dir: Option<&Path>,
+    force: bool,
+) -> Result<Fetched> {
+    if year < 2015 {
+        return Err(Error::Argument {
+            message: format!("There's no Advent of Code {}", year),
+        });
+    }
+    if !(1..=25).contains(&day) {
+        return Err(Error::Argument {
+            message: format!("Day {} isn't between 1 and 25", day),
+        });
+    }
+
+    let file = format!("day{}.input.txt", day);
+    if !force {
+        if let Ok(path) = find_input_file(&file, year, data_dir) {
+            if std::fs::metadata(&path).is_ok_and(|m| m.len() > 0) {
+                return Ok(Fetched::Cached(path));
+            }
+        }
+    }
+
+    let url = format!("{}/{}/day/{}/input", config.base_url(), year, day);
+    let response = Request::get(&url)
+        .header("Cookie", format!("session={}", config.session()?))
+        .header("User-Agent", config.user_agent())
+        .send()?;
+    if !response.is_success() {
+        return Err(Error::Http {
+            url,
+            message: format!(
+                "got {}: {}",
+                response.status,
+                response.body.lines().next().unwrap_or_default().trim()
+            ),
+        });
+    }
+
+    let dir = input_dirs(year, data_dir).remove(0);
+    let path = dir.join(&file);
+    // Written next to the input first so an interrupted download doesn't
+    // leave half an input behind
+    let partial = dir.join(format!("{}.part", file));
+    std::fs::create_dir_all(&dir)
+        .and_then(|()| std::fs::write(&partial, &response.body))
+        .and_then(|()| std::fs::rename(&partial, &path))
+        .map_err(|source| Error::Io {
+            path: path.clone(),
+            source,
+        })?;
+    Ok(Fetched::Downloaded(path))
+}
@@ -0,0 +1,299 @@
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+use crate::{Error, Input, InputSource, Result};
+
+// What a part of an answer needs so it can be read from a fixture manifest
+// and checked against what a day computes
+pub trait PartAnswer: Display + Debug + PartialEq + Sized {
+    fn from_expected(s: &str) -> std::result::Result<Self, String>;
+
+    // Unsolved parts run but are never checked
+    fn is_solved(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Display + Debug + PartialEq + FromStr> PartAnswer for T
+where
+    T::Err: Display,
+{
+    fn from_expected(s: &str) -> std::result::Result<Self, String> {
+        s.parse().map_err(|e: T::Err| e.to_string())
+    }
+}
+
+// Stands in for a part a day doesn't solve yet, e.g. `Answer(part_1, Unsolved)`.
+// Expected values for it in a manifest are read but not checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not implemented yet")
+    }
+}
+
+impl PartAnswer for Unsolved {
+    fn from_expected(_: &str) -> std::result::Result<Self, String> {
+        Ok(Unsolved)
+    }
+
+    fn is_solved(&self) -> bool {
+        false
+    }
+}
+
+// How one part did against a day's examples
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PartReport {
+    pub solved: bool,
+    // Examples with an expected value for this part that matched it
+    pub verified: usize,
+    // And the ones that didn't
+    pub failed: usize,
+    pub examples: usize,
+}
+
+impl Display for PartReport {
+    // Counts only the examples that have an answer for this part
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let checked = self.verified + self.failed;
+        if !self.solved {
+            write!(f, "skipped, not implemented yet")
+        } else if self.failed > 0 {
+            write!(f, "failed on {}/{} examples", self.failed, checked)
+        } else if self.verified == 0 {
+            write!(f, "ran, no expected answer to check")
+        } else {
+            write!(f, "verified on {}/{} examples", self.verified, checked)
+        }
+    }
+}
+
+// Both parts and what they got wrong
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExampleReport(pub PartReport, pub PartReport, pub Vec<Mismatch>);
+
+impl Display for ExampleReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Examples: part 1 {}, part 2 {}", self.0, self.1)
+    }
+}
+
+// An example answer a day got wrong. Both answers are their `{:#}` Display
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u32,
+    pub fixture: String,
+    pub part: u8,
+    pub expected: String,
+    pub found: String,
+}
+
+impl Display for Mismatch {
+    // Multi-line answers like day 10's screen get a line by line diff
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.expected.contains('\n') && !self.found.contains('\n') {
+            return write!(
+                f,
+                "Day {} part {} doesn't match the answer for {}. Expected {} but got {}",
+                self.day, self.part, self.fixture, self.expected, self.found
+            );
+        }
+
+        write!(
+            f,
+            "Day {} part {} doesn't match the answer for {} (- expected, + got):",
+            self.day, self.part, self.fixture
+        )?;
+        let expected: Vec<&str> = self.expected.lines().collect();
+        let found: Vec<&str> = self.found.lines().collect();
+        for i in 0..expected.len().max(found.len()) {
+            match (expected.get(i), found.get(i)) {
+                (Some(e), Some(g)) if e == g => write!(f, "\n    {}", e)?,
+                (e, g) => {
+                    if let Some(e) = e {
+                        write!(f, "\n  - {}", e)?;
+                    }
+                    if let Some(g) = g {
+                        write!(f, "\n  + {}", g)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// An example given on the command line, with the answers it should give as
+// they'd be written in a manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub source: InputSource,
+    pub answer_1: Option<String>,
+    pub answer_2: Option<String>,
+}
+
+// An example input file and the answers it should give. A missing answer
+// means that part isn't checked for this example
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture<T, B> {
+    pub file: String,
+    pub answer_1: Option<T>,
+    pub answer_2: Option<B>,
+}
+
+impl<T: PartAnswer, B: PartAnswer> Fixture<T, B> {
+    // Parses a `dayN.fixtures.txt` manifest, which looks like:
+    //
+    //     # Comments start with #
+    //     [day9.test.txt]
+    //     part1 = 13
+    //     part2 = 1
+    //
+    //     [day9.test2.txt]
+    //     part2 = 36
+    //
+    // Multi-line answers go between two `"""` lines
+    pub fn from_manifest(manifest: &Input) -> Result<Vec<Self>> {
+        let context = "Fixture::from_manifest";
+        let mut fixtures: Vec<Self> = Vec::new();
+        let mut lines = manifest.iter().enumerate();
+
+        while let Some((i, line)) = lines.next() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(file) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                fixtures.push(Self {
+                    file: file.trim().to_string(),
+                    answer_1: None,
+                    answer_2: None,
+                });
+                continue;
+            }
+
+            let (key, value) = match line.split_once('=') {
+                Some(v) => v,
+                None => {
+                    return Err(manifest.error(
+                        context,
+                        "Bad line",
+                        i,
+                        line,
+                        "`[<file>]`, `part1 = <answer>` or `part2 = <answer>`",
+                    ))
+                }
+            };
+            let (key, value) = (key.trim(), value.trim());
+
+            let fixture = match fixtures.last_mut() {
+                Some(v) => v,
+                None => {
+                    return Err(manifest.error(
+                        context,
+                        "Answer outside of a fixture",
+                        i,
+                        line,
+                        "a `[<file>]` line first",
+                    ))
+                }
+            };
+
+            let expected = if value == "\"\"\"" {
+                let mut block: Vec<&str> = Vec::new();
+                loop {
+                    match lines.next() {
+                        Some((_, l)) if l.trim() == "\"\"\"" => break,
+                        Some((_, l)) => block.push(l),
+                        None => {
+                            return Err(manifest.error_at_end(
+                                context,
+                                "Unclosed multi-line answer",
+                                "a closing `\"\"\"`",
+                            ))
+                        }
+                    }
+                }
+                block.join("\n")
+            } else {
+                value.to_string()
+            };
+
+            let bad_answer = |part: u8, e: String| {
+                manifest.error(
+                    context,
+                    format!("Bad part {} answer: {}", part, e),
+                    i,
+                    value,
+                    format!("a part {} answer", part),
+                )
+            };
+            match key {
+                "part1" => {
+                    fixture.answer_1 =
+                        Some(T::from_expected(&expected).map_err(|e| bad_answer(1, e))?)
+                }
+                "part2" => {
+                    fixture.answer_2 =
+                        Some(B::from_expected(&expected).map_err(|e| bad_answer(2, e))?)
+                }
+                _ => {
+                    return Err(manifest.error(
+                        context,
+                        "Unknown key",
+                        i,
+                        key,
+                        "`part1` or `part2`",
+                    ))
+                }
+            }
+        }
+
+        Ok(fixtures)
+    }
+
+    // Checks the answers of a command line example the way a manifest's are
+    pub fn from_example(example: &Example) -> Result<Self> {
+        let bad_answer = |part: u8, e: String| {
+            Error::parse(
+                "Fixture::from_example",
+                format!("Bad part {} answer for {}: {}", part, example.source, e),
+            )
+        };
+
+        Ok(Self {
+            file: example.source.to_string(),
+            answer_1: match &example.answer_1 {
+                Some(v) => Some(T::from_expected(v).map_err(|e| bad_answer(1, e))?),
+                None => None,
+            },
+            answer_2: match &example.answer_2 {
+                Some(v) => Some(B::from_expected(v).map_err(|e| bad_answer(2, e))?),
+                None => None,
+            },
+        })
+    }
+
+    // Writes the fixture back in the manifest format, leaving out parts
+    // without an answer. Answers use their `{:#}` Display
+    pub fn to_manifest(&self) -> String {
+        let mut manifest = format!("[{}]\n", self.file);
+        let answers = [
+            (1, self.answer_1.as_ref().map(|a| format!("{:#}", a))),
+            (2, self.answer_2.as_ref().map(|a| format!("{:#}", a))),
+        ];
+        for (part, answer) in answers {
+            let Some(answer) = answer else { continue };
+            if answer.contains('\n') {
+                manifest.push_str(&format!("part{} = \"\"\"\n{}\n\"\"\"\n", part, answer));
+            } else {
+                manifest.push_str(&format!("part{} = {}\n", part, answer));
+            }
+        }
+        manifest
+    }
+}
@@ -0,0 +1,61 @@
+use std::fmt::Display;
+use std::io::Write;
+use std::path::Path;
+
+use crate::{Error, Input, Result};
+
+// Benchmark baselines and submissions are kept in files that are only ever
+// appended to: a `#` header and then a line of tab separated fields per
+// record
+
+// Parses every record of `path` with `parse`, which gets the file and the
+// record's 0-based line index for error locations. A missing file has none
+pub(crate) fn load_history<R>(
+    path: &Path,
+    parse: impl Fn(&Input, usize) -> Result<R>,
+) -> Result<Vec<R>> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(v) => v,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(source) => {
+            return Err(Error::Io {
+                path: path.to_path_buf(),
+                source,
+            })
+        }
+    };
+    let file = Input::from_content(&content);
+
+    (0..file.len())
+        .filter(|i| !file[*i].is_empty() && !file[*i].starts_with('#'))
+        .map(|i| parse(&file, i))
+        .collect()
+}
+
+// Adds a line per record to the end of `path`, which starts with `header`
+// when it's new
+pub(crate) fn append_history(path: &Path, header: &str, records: &[impl Display]) -> Result<()> {
+    let is_new = !path.exists();
+    let io_error = |source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(io_error)?;
+
+    let mut lines = String::new();
+    if is_new {
+        lines.push_str(header);
+        lines.push('\n');
+    }
+    for record in records {
+        lines.push_str(&format!("{}\n", record));
+    }
+    file.write_all(lines.as_bytes()).map_err(io_error)
+}
@@ -0,0 +1,218 @@
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use crate::{Error, Result};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+// Just enough HTTP for adventofcode.com without pulling in a client. Plain
+// http:// URLs, like a local stub server, are spoken to directly and https://
+// ones go through curl
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: &'static str,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+impl Request {
+    pub fn get(url: impl Into<String>) -> Self {
+        Self {
+            method: "GET",
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    // A form POST, with `form` already urlencoded
+    pub fn post_form(url: impl Into<String>, form: impl Into<String>) -> Self {
+        Self {
+            method: "POST",
+            url: url.into(),
+            headers: vec![(
+                "Content-Type".to_string(),
+                "application/x-www-form-urlencoded".to_string(),
+            )],
+            body: Some(form.into()),
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.headers.push((name.to_string(), value.into()));
+        self
+    }
+
+    pub fn send(&self) -> Result<Response> {
+        if let Some(rest) = self.url.strip_prefix("http://") {
+            self.send_plain(rest)
+        } else if self.url.starts_with("https://") {
+            self.send_curl()
+        } else {
+            Err(self.error("only http:// and https:// URLs are supported"))
+        }
+    }
+
+    fn error(&self, message: impl std::fmt::Display) -> Error {
+        Error::Http {
+            url: self.url.clone(),
+            message: message.to_string(),
+        }
+    }
+
+    fn send_plain(&self, rest: &str) -> Result<Response> {
+        let (host, path) = match rest.find('/') {
+            Some(i) => rest.split_at(i),
+            None => (rest, "/"),
+        };
+        let address = if host.contains(':') {
+            host.to_string()
+        } else {
+            format!("{}:80", host)
+        };
+
+        let mut request = format!(
+            "{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n",
+            self.method, path, host
+        );
+        for (name, value) in &self.headers {
+            request.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        let body = self.body.as_deref().unwrap_or_default();
+        if self.body.is_some() {
+            request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+        }
+        request.push_str("\r\n");
+        request.push_str(body);
+
+        let mut response = Vec::new();
+        let exchange = TcpStream::connect(&address).and_then(|mut stream| {
+            stream.set_read_timeout(Some(TIMEOUT))?;
+            stream.set_write_timeout(Some(TIMEOUT))?;
+            stream.write_all(request.as_bytes())?;
+            stream.read_to_end(&mut response)
+        });
+        if let Err(e) = exchange {
+            return Err(self.error(e));
+        }
+        self.parse_response(&response)
+    }
+
+    fn parse_response(&self, response: &[u8]) -> Result<Response> {
+        let response = String::from_utf8_lossy(response);
+        let Some((head, body)) = response.split_once("\r\n\r\n") else {
+            return Err(self.error("the response has no end of headers"));
+        };
+        let mut head = head.lines();
+        let status = head
+            .next()
+            .and_then(|line| line.split_whitespace().nth(1))
+            .and_then(|status| status.parse().ok())
+            .ok_or_else(|| self.error("the response has no status"))?;
+
+        let chunked = head.any(|line| {
+            line.split_once(':').is_some_and(|(name, value)| {
+                name.trim().eq_ignore_ascii_case("transfer-encoding")
+                    && value.trim().eq_ignore_ascii_case("chunked")
+            })
+        });
+        let body = if chunked {
+            self.dechunk(body)?
+        } else {
+            body.to_string()
+        };
+
+        Ok(Response { status, body })
+    }
+
+    fn dechunk(&self, mut chunks: &str) -> Result<String> {
+        let mut body = String::new();
+        loop {
+            let (size, rest) = chunks
+                .split_once("\r\n")
+                .ok_or_else(|| self.error("a chunk has no size"))?;
+            let size = size.split(';').next().unwrap_or_default().trim();
+            let size = usize::from_str_radix(size, 16)
+                .map_err(|_| self.error(format!("bad chunk size `{}`", size)))?;
+            if size == 0 {
+                return Ok(body);
+            }
+            let chunk = rest
+                .get(..size)
+                .ok_or_else(|| self.error("the response ended inside a chunk"))?;
+            body.push_str(chunk);
+            chunks = rest[size..].trim_start_matches("\r\n");
+        }
+    }
+
+    // The request is handed to curl as a config on stdin, which keeps the
+    // session cookie out of the process list
+    fn send_curl(&self) -> Result<Response> {
+        let quote = |value: &str| {
+            let escaped = value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n")
+                .replace('\r', "\\r");
+            format!("\"{}\"", escaped)
+        };
+        let mut config = format!(
+            "silent\nshow-error\nrequest = {}\nurl = {}\nmax-time = {}\nwrite-out = \"\\n%{{http_code}}\"\n",
+            quote(self.method),
+            quote(&self.url),
+            TIMEOUT.as_secs()
+        );
+        for (name, value) in &self.headers {
+            config.push_str(&format!(
+                "header = {}\n",
+                quote(&format!("{}: {}", name, value))
+            ));
+        }
+        if let Some(body) = &self.body {
+            config.push_str(&format!("data-binary = {}\n", quote(body)));
+        }
+
+        let child = Command::new("curl")
+            .args(["--config", "-"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| self.error(format!("couldn't run curl: {}", e)))?;
+        let output = child
+            .stdin
+            .as_ref()
+            .map_or(Ok(()), |mut stdin| stdin.write_all(config.as_bytes()))
+            .and_then(|()| child.wait_with_output())
+            .map_err(|e| self.error(format!("curl failed: {}", e)))?;
+        if !output.status.success() {
+            return Err(self.error(String::from_utf8_lossy(&output.stderr).trim()));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = stdout.rsplit_once('\n').unwrap_or(("", &stdout));
+        let status = status
+            .trim()
+            .parse()
+            .map_err(|_| self.error("curl gave no status"))?;
+        Ok(Response {
+            status,
+            body: body.to_string(),
+        })
+    }
+}
@@ -0,0 +1,190 @@
+use std::fmt::Display;
+use std::io::{Cursor, Read};
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+use crate::error::{Error, Location};
+use crate::{InputStream, Normalization, Result};
+
+// The lines of a puzzle input. Derefs to `[String]` so days can keep
+// iterating and indexing it like the `Vec<String>` it used to be
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Input(Vec<String>);
+
+impl Input {
+    pub fn new(lines: Vec<String>) -> Self {
+        Self(lines)
+    }
+
+    pub fn into_lines(self) -> Vec<String> {
+        self.0
+    }
+
+    pub fn from_content(content: &str) -> Self {
+        Self(content.lines().map(|l| l.to_string()).collect())
+    }
+
+    pub fn read(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Ok(Self::from_content(&content)),
+            Err(source) => Err(Error::Io {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    // Builds a parse error pointing at `token` on the 0-based `line`. A line
+    // past the end of the input reports the end of the input as found
+    pub fn error(
+        &self,
+        context: &'static str,
+        message: impl Display,
+        line: usize,
+        token: &str,
+        expected: impl Display,
+    ) -> Error {
+        match self.0.get(line) {
+            Some(text) => Error::parse(context, message)
+                .found(text, token, expected)
+                .on_line(line),
+            None => self.error_at_end(context, message, expected),
+        }
+    }
+
+    // Builds a parse error for input that ended too early
+    pub fn error_at_end(
+        &self,
+        context: &'static str,
+        message: impl Display,
+        expected: impl Display,
+    ) -> Error {
+        let mut location = Location::new("", "", expected);
+        location.line = Some(self.0.len() + 1);
+        location.found = "end of input".to_string();
+
+        Error::Parse {
+            context,
+            message: message.to_string(),
+            location: Some(Box::new(location)),
+            source: None,
+        }
+    }
+}
+
+// Where an input given on the command line comes from. `-` is stdin
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl From<&str> for InputSource {
+    fn from(s: &str) -> Self {
+        match s {
+            "-" => Self::Stdin,
+            path => Self::File(path.into()),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+// Stdin can only be read once, but benchmarks read the input many times.
+// Streaming it doesn't keep it around, so it can't be read again after that
+static STDIN: OnceLock<std::result::Result<String, (std::io::ErrorKind, String)>> = OnceLock::new();
+static STDIN_STREAMED: AtomicBool = AtomicBool::new(false);
+
+fn stdin_streamed() -> Error {
+    Error::Io {
+        path: "-".into(),
+        source: std::io::Error::other(
+            "stdin was already streamed, save the input to a file to read it again",
+        ),
+    }
+}
+
+impl InputSource {
+    // Opens the input to read it as it's parsed, cleaned up as
+    // `normalization` says
+    pub fn open(&self, normalization: Normalization) -> Result<InputStream> {
+        let stream = match self {
+            Self::File(path) => InputStream::open(path)?,
+            Self::Stdin => match STDIN.get() {
+                Some(_) => InputStream::new("-", Cursor::new(stdin_content()?)),
+                None if STDIN_STREAMED.swap(true, Ordering::SeqCst) => return Err(stdin_streamed()),
+                None => InputStream::new("-", std::io::stdin().lock()),
+            },
+        };
+        stream.normalized(normalization)
+    }
+
+    // Reads the whole input. Stdin is kept around to be read again
+    pub fn read(&self, normalization: Normalization) -> Result<Input> {
+        if *self == Self::Stdin && STDIN.get().is_none() {
+            if STDIN_STREAMED.load(Ordering::SeqCst) {
+                return Err(stdin_streamed());
+            }
+            stdin_content()?;
+        }
+        self.open(normalization)?.collect()
+    }
+}
+
+fn stdin_content() -> Result<String> {
+    let content = STDIN.get_or_init(|| {
+        let mut content = String::new();
+        match std::io::stdin().read_to_string(&mut content) {
+            Ok(_) => Ok(content),
+            Err(e) => Err((e.kind(), e.to_string())),
+        }
+    });
+    match content {
+        Ok(content) => Ok(content.clone()),
+        Err((kind, message)) => Err(Error::Io {
+            path: "-".into(),
+            source: std::io::Error::new(*kind, message.clone()),
+        }),
+    }
+}
+
+impl Deref for Input {
+    type Target = [String];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Vec<String>> for Input {
+    fn from(lines: Vec<String>) -> Self {
+        Self(lines)
+    }
+}
+
+impl<'a> IntoIterator for &'a Input {
+    type Item = &'a String;
+    type IntoIter = std::slice::Iter<'a, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl IntoIterator for Input {
+    type Item = String;
+    type IntoIter = std::vec::IntoIter<String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
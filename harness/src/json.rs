@@ -0,0 +1,226 @@
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use crate::{Error, Result};
+
+// Just enough JSON to read the exports adventofcode.com hands out
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    // In the order the keys appear
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut parser = Parser {
+            text,
+            chars: text.char_indices().peekable(),
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        match parser.chars.peek() {
+            None => Ok(value),
+            Some(&(i, _)) => Err(parser.error(i, "Trailing characters after the value")),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Self::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::Number(n) if *n >= 0.0 && n.fract() == 0.0 => Some(*n as u64),
+            // Some exports quote their numbers
+            Self::String(s) => s.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn entries(&self) -> &[(String, Json)] {
+        match self {
+            Self::Object(entries) => entries,
+            _ => &[],
+        }
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl Parser<'_> {
+    fn error(&self, at: usize, message: &str) -> Error {
+        let line = self.text[..at].matches('\n').count();
+        let line_start = self.text[..at].rfind('\n').map_or(0, |i| i + 1);
+        let line_text = self.text[line_start..].lines().next().unwrap_or_default();
+        let token_len = self.text[at..].chars().next().map_or(0, char::len_utf8);
+        let token = &line_text[at - line_start..(at - line_start + token_len).min(line_text.len())];
+        Error::parse("Json::parse", message)
+            .found(line_text, token, "JSON")
+            .on_line(line)
+    }
+
+    fn end(&self) -> Error {
+        self.error(self.text.len(), "Unexpected end of JSON")
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        self.skip_whitespace();
+        match self.chars.next() {
+            Some((_, c)) if c == expected => Ok(()),
+            Some((i, _)) => Err(self.error(i, &format!("Expected `{}`", expected))),
+            None => Err(self.end()),
+        }
+    }
+
+    fn value(&mut self) -> Result<Json> {
+        self.skip_whitespace();
+        let Some(&(start, c)) = self.chars.peek() else {
+            return Err(self.end());
+        };
+        match c {
+            '{' => self.object(),
+            '[' => self.array(),
+            '"' => self.string().map(Json::String),
+            '-' | '0'..='9' => {
+                while self
+                    .chars
+                    .next_if(|(_, c)| matches!(c, '-' | '+' | '.' | 'e' | 'E' | '0'..='9'))
+                    .is_some()
+                {}
+                let end = self.chars.peek().map_or(self.text.len(), |&(i, _)| i);
+                self.text[start..end]
+                    .parse()
+                    .map(Json::Number)
+                    .map_err(|_| self.error(start, "Bad number"))
+            }
+            _ => {
+                for (word, value) in [
+                    ("null", Json::Null),
+                    ("true", Json::Bool(true)),
+                    ("false", Json::Bool(false)),
+                ] {
+                    if self.text[start..].starts_with(word) {
+                        for _ in 0..word.len() {
+                            self.chars.next();
+                        }
+                        return Ok(value);
+                    }
+                }
+                Err(self.error(start, "Expected a value"))
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Json> {
+        self.expect('{')?;
+        let mut entries = Vec::new();
+        self.skip_whitespace();
+        if self.chars.next_if(|(_, c)| *c == '}').is_some() {
+            return Ok(Json::Object(entries));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.expect(':')?;
+            entries.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some((_, ',')) => continue,
+                Some((_, '}')) => return Ok(Json::Object(entries)),
+                Some((i, _)) => return Err(self.error(i, "Expected `,` or `}`")),
+                None => return Err(self.end()),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json> {
+        self.expect('[')?;
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.chars.next_if(|(_, c)| *c == ']').is_some() {
+            return Ok(Json::Array(values));
+        }
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some((_, ',')) => continue,
+                Some((_, ']')) => return Ok(Json::Array(values)),
+                Some((i, _)) => return Err(self.error(i, "Expected `,` or `]`")),
+                None => return Err(self.end()),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        match self.chars.next() {
+            Some((_, '"')) => {}
+            Some((i, _)) => return Err(self.error(i, "Expected a string")),
+            None => return Err(self.end()),
+        }
+        let mut s = String::new();
+        loop {
+            match self.chars.next() {
+                Some((_, '"')) => return Ok(s),
+                Some((i, '\\')) => match self.chars.next() {
+                    Some((_, '"')) => s.push('"'),
+                    Some((_, '\\')) => s.push('\\'),
+                    Some((_, '/')) => s.push('/'),
+                    Some((_, 'b')) => s.push('\u{8}'),
+                    Some((_, 'f')) => s.push('\u{c}'),
+                    Some((_, 'n')) => s.push('\n'),
+                    Some((_, 'r')) => s.push('\r'),
+                    Some((_, 't')) => s.push('\t'),
+                    Some((_, 'u')) => s.push(self.unicode_escape(i)?),
+                    Some((i, _)) => return Err(self.error(i, "Bad escape")),
+                    None => return Err(self.end()),
+                },
+                Some((_, c)) => s.push(c),
+                None => return Err(self.end()),
+            }
+        }
+    }
+
+    // The 4 hex digits after `\u`, and the low half of a surrogate pair
+    fn unicode_escape(&mut self, at: usize) -> Result<char> {
+        let hex = |parser: &mut Self| -> Result<u32> {
+            let digits: String = (0..4)
+                .filter_map(|_| parser.chars.next())
+                .map(|(_, c)| c)
+                .collect();
+            u32::from_str_radix(&digits, 16).map_err(|_| parser.error(at, "Bad unicode escape"))
+        };
+        let high = hex(self)?;
+        let code = if (0xD800..0xDC00).contains(&high) {
+            self.expect('\\')?;
+            self.expect('u')?;
+            let low = hex(self)?;
+            0x10000 + ((high - 0xD800) << 10) + (low.wrapping_sub(0xDC00) & 0x3FF)
+        } else {
+            high
+        };
+        char::from_u32(code).ok_or_else(|| self.error(at, "Bad unicode escape"))
+    }
+}
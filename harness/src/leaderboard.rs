@@ -0,0 +1,421 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::output::{csv_field, json_string};
+use crate::{data_dirs, Config, Error, Format, Json, Request, Result};
+
+// adventofcode.com asks for private leaderboards not to be downloaded more
+// often than this
+const REFRESH_AFTER: Duration = Duration::from_secs(15 * 60);
+
+const CSV_HEADER: &str = "year,rank,rank_change,id,name,score,stars,day,part1_s,part2_s,delta_s";
+
+// A member of a private leaderboard and when they got each star
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    // Unix times of the part 1 and part 2 stars of each day
+    pub stars: BTreeMap<u32, [Option<u64>; 2]>,
+}
+
+impl Member {
+    pub fn star_count(&self) -> usize {
+        self.stars.values().flatten().flatten().count()
+    }
+}
+
+// A private leaderboard as exported by adventofcode.com's JSON API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub year: u32,
+    pub members: Vec<Member>,
+}
+
+// Where a member stands after some day. `member` indexes Leaderboard::members
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Standing {
+    pub member: usize,
+    pub rank: usize,
+    // How many places they went up since the day before, down when negative
+    pub rank_change: i64,
+    pub score: usize,
+}
+
+// When a day's puzzle comes out, midnight in US Eastern time
+pub fn unlock_time(year: u32, day: u32) -> u64 {
+    // Days since 1970-01-01 of December `day`, from Howard Hinnant's
+    // days_from_civil
+    let year = year as u64;
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = 275 + day as u64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+    days * 86400 + 5 * 3600
+}
+
+// As hours:minutes:seconds, with hours going past 24
+fn clock(seconds: u64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+// `data/<year>/leaderboard-<id>.json`, where a downloaded leaderboard is kept
+pub fn leaderboard_file(year: u32, id: &str, data_dir: Option<&Path>) -> PathBuf {
+    data_dirs(year, data_dir)
+        .remove(0)
+        .join(format!("leaderboard-{}.json", id))
+}
+
+// Downloads a private leaderboard to `path`, unless the copy there is recent
+// enough, and returns its content
+pub fn fetch_leaderboard(config: &Config, year: u32, id: &str, path: &Path) -> Result<String> {
+    let io_error = |source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    let age = std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+    if age.is_some_and(|age| age < REFRESH_AFTER) {
+        return std::fs::read_to_string(path).map_err(io_error);
+    }
+
+    let url = format!(
+        "{}/{}/leaderboard/private/view/{}.json",
+        config.base_url(),
+        year,
+        id
+    );
+    let response = Request::get(&url)
+        .header("Cookie", format!("session={}", config.session()?))
+        .header("User-Agent", config.user_agent())
+        .send()?;
+    // A session that isn't a member gets the login page instead
+    if !response.is_success() || !response.body.trim_start().starts_with('{') {
+        return Err(Error::Http {
+            url,
+            message: format!(
+                "got {} and no leaderboard, check the id and the session",
+                response.status
+            ),
+        });
+    }
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    std::fs::write(path, &response.body).map_err(io_error)?;
+    Ok(response.body)
+}
+
+impl Leaderboard {
+    pub fn load(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content),
+            Err(source) => Err(Error::Io {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    // Reads the `event` and the `completion_day_level` of each member
+    pub fn parse(content: &str) -> Result<Self> {
+        let context = "Leaderboard::parse";
+        let json = Json::parse(content)?;
+        let year = json
+            .get("event")
+            .and_then(Json::as_u64)
+            .ok_or_else(|| Error::parse(context, "No `event` year"))? as u32;
+        let Some(Json::Object(members)) = json.get("members") else {
+            return Err(Error::parse(context, "No `members` object"));
+        };
+
+        let mut parsed = Vec::new();
+        for (key, member) in members {
+            let id = member
+                .get("id")
+                .and_then(Json::as_u64)
+                .or_else(|| key.parse().ok())
+                .ok_or_else(|| Error::parse(context, format!("Member `{}` has no id", key)))?;
+            let name = match member.get("name").and_then(Json::as_str) {
+                Some(name) => name.to_string(),
+                None => format!("(anonymous user #{})", id),
+            };
+
+            let mut stars = BTreeMap::new();
+            let days = member
+                .get("completion_day_level")
+                .map_or(&[][..], Json::entries);
+            for (day, parts) in days {
+                let day: u32 = day.parse().map_err(|_| {
+                    Error::parse(context, format!("Member {} has a day `{}`", id, day))
+                })?;
+                let star = |part: &str| {
+                    parts
+                        .get(part)
+                        .and_then(|p| p.get("get_star_ts"))
+                        .and_then(Json::as_u64)
+                };
+                stars.insert(day, [star("1"), star("2")]);
+            }
+
+            parsed.push(Member { id, name, stars });
+        }
+
+        Ok(Self {
+            year,
+            members: parsed,
+        })
+    }
+
+    // The last day anyone got a star on
+    pub fn last_day(&self) -> u32 {
+        self.members
+            .iter()
+            .filter_map(|m| m.stars.keys().next_back())
+            .max()
+            .copied()
+            .unwrap_or(0)
+    }
+
+    // Local scores counting days up to `day`: the first of N members to get a
+    // star gets N points, the next N - 1 and so on
+    fn scores(&self, day: u32) -> Vec<usize> {
+        let count = self.members.len();
+        let mut scores = vec![0; count];
+        for d in 1..=day {
+            for part in 0..2 {
+                let mut times: Vec<(u64, usize)> = self
+                    .members
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, m)| Some((m.stars.get(&d)?[part]?, i)))
+                    .collect();
+                times.sort_unstable();
+                for (place, (_, member)) in times.into_iter().enumerate() {
+                    scores[member] += count - place;
+                }
+            }
+        }
+        scores
+    }
+
+    // Member indices from first to last after `day`, ties broken by stars
+    // and then by id
+    fn ranking(&self, day: u32) -> (Vec<usize>, Vec<usize>) {
+        let scores = self.scores(day);
+        let stars: Vec<usize> = self
+            .members
+            .iter()
+            .map(|m| m.stars.range(..=day).flat_map(|(_, s)| s).flatten().count())
+            .collect();
+        let mut order: Vec<usize> = (0..self.members.len()).collect();
+        order.sort_by_key(|&i| {
+            (
+                std::cmp::Reverse(scores[i]),
+                std::cmp::Reverse(stars[i]),
+                self.members[i].id,
+            )
+        });
+        (order, scores)
+    }
+
+    // The standings after the last day, compared with the day before it
+    pub fn standings(&self) -> Vec<Standing> {
+        let last_day = self.last_day();
+        let (order, scores) = self.ranking(last_day);
+        let (before, _) = self.ranking(last_day.saturating_sub(1));
+        let rank_before = |member| before.iter().position(|&m| m == member).unwrap_or(0) + 1;
+
+        order
+            .iter()
+            .enumerate()
+            .map(|(i, &member)| Standing {
+                member,
+                rank: i + 1,
+                rank_change: rank_before(member) as i64 - (i + 1) as i64,
+                score: scores[member],
+            })
+            .collect()
+    }
+
+    // How long after the unlock each part of a day took, and part 2 after
+    // part 1
+    fn times(&self, member: &Member, day: u32) -> [Option<u64>; 3] {
+        let unlock = unlock_time(self.year, day);
+        let [part1, part2] = member.stars.get(&day).copied().unwrap_or_default();
+        let since_unlock = |t: Option<u64>| t.map(|t| t.saturating_sub(unlock));
+        let delta = part1.zip(part2).map(|(a, b)| b.saturating_sub(a));
+        [since_unlock(part1), since_unlock(part2), delta]
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Text => self.to_text(),
+            Format::Json => self.to_json(),
+            Format::Csv => self.to_csv(),
+        }
+    }
+
+    fn to_text(&self) -> String {
+        let standings = self.standings();
+        let last_day = self.last_day();
+        let width = self
+            .members
+            .iter()
+            .map(|m| m.name.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(4);
+        let mut lines = vec![
+            format!(
+                "Leaderboard {}, {} members after day {}",
+                self.year,
+                self.members.len(),
+                last_day
+            ),
+            String::new(),
+            format!(
+                "  {:>4}  {:>6}  {:>5}  {:>5}  Name",
+                "Rank", "Change", "Score", "Stars"
+            ),
+        ];
+        for standing in &standings {
+            let member = &self.members[standing.member];
+            let change = match standing.rank_change {
+                0 => "=".to_string(),
+                change => format!("{:+}", change),
+            };
+            lines.push(format!(
+                "  {:>4}  {:>6}  {:>5}  {:>5}  {}",
+                standing.rank,
+                change,
+                standing.score,
+                member.star_count(),
+                member.name
+            ));
+        }
+
+        let time = |t: Option<u64>| t.map_or("-".to_string(), clock);
+        for day in 1..=last_day {
+            let mut rows: Vec<(&Member, [Option<u64>; 3])> = self
+                .members
+                .iter()
+                .filter(|m| m.stars.contains_key(&day))
+                .map(|m| (m, self.times(m, day)))
+                .collect();
+            if rows.is_empty() {
+                continue;
+            }
+            rows.sort_by_key(|(m, [part1, part2, _])| {
+                (part2.unwrap_or(u64::MAX), part1.unwrap_or(u64::MAX), m.id)
+            });
+
+            lines.push(String::new());
+            lines.push(format!(
+                "Day {:<w$}  {:>9}  {:>9}  {:>9}",
+                day,
+                "Part 1",
+                "Part 2",
+                "Delta",
+                w = width - 2
+            ));
+            for (member, [part1, part2, delta]) in rows {
+                let padding = width - member.name.chars().count();
+                lines.push(format!(
+                    "  {}{}  {:>9}  {:>9}  {:>9}",
+                    member.name,
+                    " ".repeat(padding),
+                    time(part1),
+                    time(part2),
+                    time(delta)
+                ));
+            }
+        }
+        lines.join("\n")
+    }
+
+    fn to_json(&self) -> String {
+        let number = |t: Option<u64>| t.map_or("null".to_string(), |t| t.to_string());
+        let members: Vec<String> = self
+            .standings()
+            .iter()
+            .map(|standing| {
+                let member = &self.members[standing.member];
+                let days: Vec<String> = member
+                    .stars
+                    .keys()
+                    .map(|&day| {
+                        let [part1, part2, delta] = self.times(member, day);
+                        format!(
+                            "{{\"day\": {}, \"part1_s\": {}, \"part2_s\": {}, \"delta_s\": {}}}",
+                            day,
+                            number(part1),
+                            number(part2),
+                            number(delta)
+                        )
+                    })
+                    .collect();
+                format!(
+                    "    {{\"rank\": {}, \"rank_change\": {}, \"id\": {}, \"name\": {}, \"score\": {}, \"stars\": {}, \"days\": [{}]}}",
+                    standing.rank,
+                    standing.rank_change,
+                    member.id,
+                    json_string(&member.name),
+                    standing.score,
+                    member.star_count(),
+                    days.join(", ")
+                )
+            })
+            .collect();
+        format!(
+            "{{\n  \"year\": {},\n  \"members\": [\n{}\n  ]\n}}",
+            self.year,
+            members.join(",\n")
+        )
+    }
+
+    // A row per member and day they got a star on, or a single row without a
+    // day for members without stars
+    fn to_csv(&self) -> String {
+        let number = |t: Option<u64>| t.map(|t| t.to_string()).unwrap_or_default();
+        let mut rows = vec![CSV_HEADER.to_string()];
+        for standing in self.standings() {
+            let member = &self.members[standing.member];
+            let prefix = format!(
+                "{},{},{},{},{},{},{}",
+                self.year,
+                standing.rank,
+                standing.rank_change,
+                member.id,
+                csv_field(&member.name),
+                standing.score,
+                member.star_count()
+            );
+            if member.stars.is_empty() {
+                rows.push(format!("{},,,,", prefix));
+            }
+            for &day in member.stars.keys() {
+                let [part1, part2, delta] = self.times(member, day);
+                rows.push(format!(
+                    "{},{},{},{},{}",
+                    prefix,
+                    day,
+                    number(part1),
+                    number(part2),
+                    number(delta)
+                ));
+            }
+        }
+        rows.join("\n")
+    }
+}
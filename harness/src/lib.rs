@@ -0,0 +1,606 @@
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::path::PathBuf;
+
+mod baseline;
+mod config;
+mod data;
+mod error;
+mod fetch;
+mod fixture;
+mod hash;
+mod history;
+mod http;
+mod input;
+mod json;
+mod leaderboard;
+mod output;
+mod registry;
+mod runner;
+mod scaffold;
+mod solution;
+mod stream;
+mod submit;
+mod timing;
+
+pub use baseline::{baselines_file, git_revision, Baselines, Comparison, Record};
+pub use config::{config_file, Config, BASE_URL_VAR, CONFIG_VAR, DEFAULT_BASE_URL, SESSION_VAR};
+pub use data::{
+    answer_key, data_dirs, find_data_file, find_input_file, input_dirs, migrate_inputs, vault_dir,
+    workspace_dir, ANSWER_KEY_FILE, ANSWER_KEY_VAR, DATA_DIR_VAR, VAULT_VAR,
+};
+pub use error::{report, Error, Location, Result};
+pub use fetch::{fetch_input, Fetched};
+pub use fixture::{Example, ExampleReport, Fixture, Mismatch, PartAnswer, PartReport, Unsolved};
+pub use hash::{answer_hash, hmac_sha256, sha256, to_hex};
+pub use http::{Request, Response};
+pub use input::{Input, InputSource};
+pub use json::Json;
+pub use leaderboard::{
+    fetch_leaderboard, leaderboard_file, unlock_time, Leaderboard, Member, Standing,
+};
+pub use output::{render, Format, PartResult, Status};
+pub use registry::{Day, DaySelection, Puzzle, Registry};
+pub use runner::runner;
+pub use scaffold::scaffold;
+pub use solution::{FnSolution, Solution};
+pub use stream::{InputStream, Lines, Normalization, NormalizationReport, Whitespace};
+pub use submit::{
+    parse_response, submissions_file, submit_answer, Outcome, Submission, Submissions,
+};
+pub use timing::{time, Bench, BenchTable, Stage, Stats, Time, Timings};
+
+// How AoC runs a day. The default is what the dayN binaries do
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    // Print how long reading, parsing and each part took
+    pub timed: bool,
+    pub format: Format,
+    // Read data files from here instead of the places data_dirs lists
+    pub data_dir: Option<PathBuf>,
+    // Solve this instead of `dayN.input.txt`. It has no accepted answers
+    pub input: Option<InputSource>,
+    // Checked along with the examples in the manifest
+    pub example: Option<Example>,
+    // Show hashes of the answers instead of the answers, see answer_hash.
+    // Submitting still sends the answer itself, it only shows the hash
+    pub spoiler_safe: bool,
+    // Fail when a real input answer isn't the accepted one anymore, or when
+    // there's no answer key to tell
+    pub verify: bool,
+}
+
+// Turns on spoiler-safe output for every run, like for CI logs
+pub const SPOILER_SAFE_VAR: &str = "AOC_SPOILER_SAFE";
+
+// Set to anything but empty or 0
+pub fn spoiler_safe_from_env() -> bool {
+    std::env::var(SPOILER_SAFE_VAR).is_ok_and(|v| !v.is_empty() && v != "0")
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Answer<T: Display, B: Display>(pub T, pub B);
+
+// The examples a day is checked against live in `data/<year>/dayN.fixtures.txt`,
+// see Fixture::from_manifest for its format
+#[derive(Debug)]
+pub struct AoC<T: Display, B: Display> {
+    pub year: u32,
+    pub day: u32,
+    pub options: Options,
+    answers: PhantomData<(T, B)>,
+}
+
+impl<T: PartAnswer, B: PartAnswer> Display for Answer<T, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{\n    Part 1: {}\n    Part 2: {}\n}}", self.0, self.1)
+    }
+}
+
+impl<T: PartAnswer, B: PartAnswer> AoC<T, B> {
+    pub fn new(year: u32, day: u32) -> Self {
+        Self {
+            year,
+            day,
+            options: Options {
+                spoiler_safe: spoiler_safe_from_env(),
+                ..Options::default()
+            },
+            answers: PhantomData,
+        }
+    }
+
+    pub fn with_options(mut self, options: Options) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn fixtures(&self) -> Result<Vec<Fixture<T, B>>> {
+        Fixture::from_manifest(&self.read_input(&format!("day{}.fixtures.txt", self.day))?)
+    }
+
+    // The hashes of the answers adventofcode.com accepted for the real input.
+    // They live in `data/<year>/dayN.answers.txt`, a manifest with a single
+    // `[dayN.input.txt]`
+    pub fn accepted_answers(&self) -> Result<Option<Fixture<String, String>>> {
+        if self.options.input.is_some() {
+            return Ok(None);
+        }
+        let path = match find_data_file(
+            &self.answers_file(),
+            self.year,
+            self.options.data_dir.as_deref(),
+        ) {
+            Ok(v) => v,
+            Err(Error::NotFound { .. }) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        let input_file = self.input_file();
+        Ok(Fixture::from_manifest(&Input::read(&path)?)?
+            .into_iter()
+            .find(|f| f.file == input_file))
+    }
+
+    // Records the hashes of what the solved parts give for the real input as
+    // their accepted answers, creating the year's answer key if there's none.
+    // A part that isn't solved keeps the answer it had
+    pub fn accept<S: Solution<Part1 = T, Part2 = B>>(&self, solution: &S) -> Result<PathBuf> {
+        self.check_examples(solution)?;
+        let (Answer(answer_1, answer_2), _) = self.timed_compute(solution)?;
+        let key = answer_key(self.year, self.options.data_dir.as_deref(), true)?;
+
+        // Answers stay with the examples even when the input is in the vault
+        let data_dir = self.options.data_dir.as_deref();
+        let (path, mut manifest) = match find_data_file(&self.answers_file(), self.year, data_dir) {
+            // Read as text, an unsolved part's type can't hold its old answer
+            Ok(path) => {
+                let manifest = Fixture::<String, String>::from_manifest(&Input::read(&path)?)?;
+                (path, manifest)
+            }
+            Err(_) => {
+                let dir = data_dirs(self.year, data_dir).remove(0);
+                (dir.join(self.answers_file()), Vec::new())
+            }
+        };
+        let input_file = self.input_file();
+        let answers = match manifest.iter().position(|f| f.file == input_file) {
+            Some(i) => &mut manifest[i],
+            None => {
+                manifest.push(Fixture {
+                    file: input_file,
+                    answer_1: None,
+                    answer_2: None,
+                });
+                manifest.last_mut().unwrap()
+            }
+        };
+        if answer_1.is_solved() {
+            answers.answer_1 = Some(answer_hash(&key, &format!("{:#}", answer_1)));
+        }
+        if answer_2.is_solved() {
+            answers.answer_2 = Some(answer_hash(&key, &format!("{:#}", answer_2)));
+        }
+        let content = format!(
+            "# Hashes of the answers accepted by adventofcode.com, checked by `aoc run --verify`\n# They're keyed with {}, which is kept with the inputs\n{}",
+            ANSWER_KEY_FILE,
+            manifest
+                .iter()
+                .map(Fixture::to_manifest)
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+
+        match std::fs::write(&path, content) {
+            Ok(()) => Ok(path),
+            Err(source) => Err(Error::Io { path, source }),
+        }
+    }
+
+    // Runs every example and checks the parts that are solved and have an
+    // expected value in the manifest. The example from the options runs last
+    pub fn test_compute<S: Solution<Part1 = T, Part2 = B>>(
+        &self,
+        solution: &S,
+    ) -> Result<ExampleReport> {
+        let mut checks = Vec::new();
+        for fixture in self.fixtures()? {
+            checks.push(self.test_fixture(solution, &fixture)?);
+        }
+        if let Some(example) = &self.options.example {
+            let fixture = Fixture::from_example(example)?;
+            checks.push(self.check_fixture(solution, &fixture, &example.source)?);
+        }
+
+        let mut report = ExampleReport(
+            PartReport {
+                examples: checks.len(),
+                ..Default::default()
+            },
+            PartReport {
+                examples: checks.len(),
+                ..Default::default()
+            },
+            Vec::new(),
+        );
+        for ExampleReport(part_1, part_2, mismatches) in checks {
+            report.0.solved |= part_1.solved;
+            report.0.verified += part_1.verified;
+            report.0.failed += part_1.failed;
+            report.1.solved |= part_2.solved;
+            report.1.verified += part_2.verified;
+            report.1.failed += part_2.failed;
+            report.2.extend(mismatches);
+        }
+
+        Ok(report)
+    }
+
+    // Like test_compute, but a wrong example answer is an error. For what
+    // would be pointless on a day that doesn't pass its examples
+    fn check_examples<S: Solution<Part1 = T, Part2 = B>>(
+        &self,
+        solution: &S,
+    ) -> Result<ExampleReport> {
+        let report = self.test_compute(solution)?;
+        if report.2.is_empty() {
+            return Ok(report);
+        }
+        for mismatch in &report.2 {
+            eprintln!("{}", mismatch);
+        }
+        let mut parts: Vec<u8> = report.2.iter().map(|m| m.part).collect();
+        parts.sort_unstable();
+        parts.dedup();
+        Err(Error::TestMismatch {
+            day: self.day,
+            parts,
+        })
+    }
+
+    // Runs a single example, see test_compute
+    pub fn test_fixture<S: Solution<Part1 = T, Part2 = B>>(
+        &self,
+        solution: &S,
+        fixture: &Fixture<T, B>,
+    ) -> Result<ExampleReport> {
+        let path = find_data_file(&fixture.file, self.year, self.options.data_dir.as_deref())?;
+        self.check_fixture(solution, fixture, &InputSource::File(path))
+    }
+
+    fn check_fixture<S: Solution<Part1 = T, Part2 = B>>(
+        &self,
+        solution: &S,
+        fixture: &Fixture<T, B>,
+        source: &InputSource,
+    ) -> Result<ExampleReport> {
+        let mut input = source.open(S::NORMALIZATION)?;
+        let parsed = solution.parse_stream(&mut input);
+        warn_normalized(source, input.report());
+        let parsed = parsed?;
+        let (answer_1, answer_2) = (solution.part1(&parsed)?, solution.part2(&parsed)?);
+        let mut report = ExampleReport(
+            PartReport {
+                solved: answer_1.is_solved(),
+                examples: 1,
+                ..Default::default()
+            },
+            PartReport {
+                solved: answer_2.is_solved(),
+                examples: 1,
+                ..Default::default()
+            },
+            Vec::new(),
+        );
+
+        if let Some(expected) = fixture.answer_1.as_ref().filter(|_| report.0.solved) {
+            if answer_1 == *expected {
+                report.0.verified = 1;
+            } else {
+                report.0.failed = 1;
+                report
+                    .2
+                    .push(self.mismatch(&fixture.file, 1, expected, &answer_1));
+            }
+        }
+        if let Some(expected) = fixture.answer_2.as_ref().filter(|_| report.1.solved) {
+            if answer_2 == *expected {
+                report.1.verified = 1;
+            } else {
+                report.1.failed = 1;
+                report
+                    .2
+                    .push(self.mismatch(&fixture.file, 2, expected, &answer_2));
+            }
+        }
+
+        Ok(report)
+    }
+
+    // Fails after printing everything when a part got an example wrong
+    pub fn compute<S: Solution<Part1 = T, Part2 = B>>(&self, solution: &S) -> Result<()> {
+        let parts: Vec<u8> = self
+            .run(solution)?
+            .iter()
+            .filter(|r| r.status == Status::Failed)
+            .map(|r| r.part)
+            .collect();
+        if parts.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::TestMismatch {
+                day: self.day,
+                parts,
+            })
+        }
+    }
+
+    // Only prints in the text format, apart from wrong example answers which
+    // go to stderr otherwise. The real input is solved even when examples
+    // fail. The results of both parts are returned either way so callers can
+    // render them as JSON or CSV
+    pub fn run<S: Solution<Part1 = T, Part2 = B>>(&self, solution: &S) -> Result<Vec<PartResult>> {
+        let (results, text) = self.run_captured(solution)?;
+        print!("{}", text);
+        Ok(results)
+    }
+
+    // Like run, but hands back what it would print instead, for callers that
+    // decide whether it's shown
+    pub fn run_captured<S: Solution<Part1 = T, Part2 = B>>(
+        &self,
+        solution: &S,
+    ) -> Result<(Vec<PartResult>, String)> {
+        let options = &self.options;
+        let mut report = self.test_compute(solution)?;
+
+        let (answer, timings) = self.timed_compute(solution)?;
+        // Examples can't tell when there are none, the answer always can
+        report.0.solved = answer.0.is_solved();
+        report.1.solved = answer.1.is_solved();
+        let mut accepted = self.accepted_answers()?;
+        let key = self.hash_key(accepted.is_some())?;
+        if key.is_none() {
+            accepted = None;
+        }
+        let hash = |solved: bool, answer: &dyn Display| {
+            key.as_deref()
+                .filter(|_| solved)
+                .map(|key| answer_hash(key, &format!("{:#}", answer)))
+        };
+        let (accepted_1, accepted_2) = accepted
+            .map(|f| (f.answer_1, f.answer_2))
+            .unwrap_or_default();
+        let mut results = vec![
+            PartResult {
+                hash: hash(answer.0.is_solved(), &answer.0),
+                accepted: accepted_1,
+                ..self.part_result(1, &answer.0, report.0, timings.part_1)
+            },
+            PartResult {
+                hash: hash(answer.1.is_solved(), &answer.1),
+                accepted: accepted_2,
+                ..self.part_result(2, &answer.1, report.1, timings.part_2)
+            },
+        ];
+        // Replaced before anything sees them, so no format or report can leak
+        // an answer
+        if options.spoiler_safe {
+            for result in &mut results {
+                if let Some(hash) = &result.hash {
+                    result.answer = hash.clone();
+                }
+            }
+        }
+
+        let mut text = String::new();
+        if options.format == Format::Text {
+            if options.spoiler_safe {
+                text.push_str(&self.hashes_text(&results));
+            } else {
+                text.push_str(&self.answer_text(answer));
+            }
+            text.push_str(&format!("{}\n", report));
+            for mismatch in &report.2 {
+                text.push_str(&format!("{}\n", mismatch));
+            }
+            if options.timed {
+                text.push_str(&format!("{}\n", timings));
+            }
+        } else {
+            for mismatch in &report.2 {
+                eprintln!("{}", mismatch);
+            }
+        }
+
+        Ok((results, text))
+    }
+
+    // The key to hash answers with, when they're hidden or there are accepted
+    // ones to check. Only hiding and verifying fail without it, a plain run
+    // just doesn't check the accepted answers
+    fn hash_key(&self, has_accepted: bool) -> Result<Option<Vec<u8>>> {
+        let options = &self.options;
+        if !has_accepted && !options.spoiler_safe {
+            return Ok(None);
+        }
+        match answer_key(self.year, options.data_dir.as_deref(), options.spoiler_safe) {
+            Ok(key) => Ok(Some(key)),
+            Err(e @ Error::Config { .. }) if !options.spoiler_safe && !options.verify => {
+                eprintln!(
+                    "Warning: the accepted answers of day {} aren't checked. {}",
+                    self.day, e
+                );
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    fn part_result(
+        &self,
+        part: u8,
+        answer: &dyn Display,
+        report: PartReport,
+        time: std::time::Duration,
+    ) -> PartResult {
+        PartResult {
+            year: self.year,
+            day: self.day,
+            part,
+            answer: format!("{:#}", answer),
+            hash: None,
+            status: report.into(),
+            accepted: None,
+            time,
+        }
+    }
+
+    // Solves the real input, timing each stage on its own
+    pub fn timed_compute<S: Solution<Part1 = T, Part2 = B>>(
+        &self,
+        solution: &S,
+    ) -> Result<(Answer<T, B>, Timings)> {
+        self.measure(solution, true)
+    }
+
+    // Only warns about what normalising the input changed if `warn`, so
+    // benchmarks don't repeat it for every run
+    fn measure<S: Solution<Part1 = T, Part2 = B>>(
+        &self,
+        solution: &S,
+        warn: bool,
+    ) -> Result<(Answer<T, B>, Timings)> {
+        let source = match &self.options.input {
+            Some(source) => source.clone(),
+            None => InputSource::File(find_input_file(
+                &self.input_file(),
+                self.year,
+                self.options.data_dir.as_deref(),
+            )?),
+        };
+        let (parsed, normalized, read, parse) = if S::STREAMS {
+            let (input, read) = time(|| source.open(S::NORMALIZATION));
+            let mut input = input?;
+            let (parsed, parse) = time(|| solution.parse_stream(&mut input));
+            (parsed, input.report(), read, parse)
+        } else {
+            let (input, read) = time(|| {
+                let mut input = source.open(S::NORMALIZATION)?;
+                Ok((input.collect()?, input.report()))
+            });
+            let (input, normalized) = input?;
+            let (parsed, parse) = time(|| solution.parse(input));
+            (parsed, normalized, read, parse)
+        };
+        if warn {
+            warn_normalized(&source, normalized);
+        }
+        let parsed = parsed?;
+        let (answer_1, part_1) = time(|| solution.part1(&parsed));
+        let (answer_2, part_2) = time(|| solution.part2(&parsed));
+
+        Ok((
+            Answer(answer_1?, answer_2?),
+            Timings {
+                read,
+                parse,
+                part_1,
+                part_2,
+            },
+        ))
+    }
+
+    // Checks the examples once and then solves the real input `runs` times
+    pub fn bench<S: Solution<Part1 = T, Part2 = B>>(
+        &self,
+        solution: &S,
+        runs: usize,
+    ) -> Result<Bench> {
+        self.check_examples(solution)?;
+        // Keeps stdin around to read it again, streaming it would consume it
+        if let Some(InputSource::Stdin) = &self.options.input {
+            InputSource::Stdin.read(Normalization::NONE)?;
+        }
+
+        let runs = (0..runs)
+            .map(|i| self.measure(solution, i == 0).map(|(_, timings)| timings))
+            .collect::<Result<_>>()?;
+
+        Ok(Bench {
+            day: self.day,
+            runs,
+        })
+    }
+
+    fn input_file(&self) -> String {
+        format!("day{}.input.txt", self.day)
+    }
+
+    fn answers_file(&self) -> String {
+        format!("day{}.answers.txt", self.day)
+    }
+
+    fn read_input(&self, file: &str) -> Result<Input> {
+        Input::read(&find_data_file(
+            file,
+            self.year,
+            self.options.data_dir.as_deref(),
+        )?)
+    }
+
+    fn mismatch(
+        &self,
+        fixture: &str,
+        part: u8,
+        expected: &dyn Display,
+        found: &dyn Display,
+    ) -> Mismatch {
+        Mismatch {
+            day: self.day,
+            fixture: fixture.to_string(),
+            part,
+            expected: format!("{:#}", expected),
+            found: format!("{:#}", found),
+        }
+    }
+
+    fn answer_text(&self, answer: Answer<T, B>) -> String {
+        format!(
+            "{}\nAnswer from day {}: {}\n",
+            "🎄".repeat(self.day.try_into().unwrap()),
+            self.day,
+            answer
+        )
+    }
+
+    // The spoiler-safe answer, with whether it's the accepted one
+    fn hashes_text(&self, results: &[PartResult]) -> String {
+        let mut text = format!(
+            "{}\nAnswer hashes from day {}: {{\n",
+            "🎄".repeat(self.day.try_into().unwrap()),
+            self.day
+        );
+        for result in results {
+            let accepted = match &result.accepted {
+                _ if result.hash.is_none() => "",
+                Some(_) if result.changed() => ", doesn't match the accepted answer",
+                Some(_) => ", matches the accepted answer",
+                None => ", no accepted answer yet",
+            };
+            text.push_str(&format!(
+                "    Part {}: {}{}\n",
+                result.part, result.answer, accepted
+            ));
+        }
+        text.push_str("}\n");
+        text
+    }
+}
+
+// Says what cleaning up `source` changed, if anything
+fn warn_normalized(source: &InputSource, report: NormalizationReport) {
+    if !report.is_empty() {
+        eprintln!("Warning: normalised {}: {}", source, report);
+    }
+}
@@ -0,0 +1,175 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::PartReport;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    // The 🎄 banner and the answer tree
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err("Format::from_str::Expected text, json or csv"),
+        }
+    }
+}
+
+// Whether a part's answer was checked against the examples, and how it went
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Verified,
+    Unchecked,
+    Unsolved,
+    // It got an example wrong
+    Failed,
+}
+
+impl From<PartReport> for Status {
+    fn from(report: PartReport) -> Self {
+        if !report.solved {
+            Self::Unsolved
+        } else if report.failed > 0 {
+            Self::Failed
+        } else if report.verified == 0 {
+            Self::Unchecked
+        } else {
+            Self::Verified
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Verified => "verified",
+            Self::Unchecked => "unchecked",
+            Self::Unsolved => "unsolved",
+            Self::Failed => "failed",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// The answer to one part of a day on the real input, as its `{:#}` Display.
+// Multi-line answers like day 10's screen keep their newlines and are escaped
+// by each format
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    // The answer_hash of the answer, when it's solved and there was a key to
+    // hash it with
+    pub hash: Option<String>,
+    pub status: Status,
+    // The hash of the answer adventofcode.com accepted for this part, if it
+    // was recorded
+    pub accepted: Option<String>,
+    pub time: Duration,
+}
+
+const CSV_HEADER: &str = "year,day,part,answer,hash,status,accepted,time_ns";
+
+impl PartResult {
+    // The answer is no longer the one that was accepted
+    pub fn changed(&self) -> bool {
+        self.accepted.is_some() && self.accepted != self.hash
+    }
+
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"hash\": {}, \"status\": \"{}\", \"accepted\": {}, \"time_ns\": {}}}",
+            self.year,
+            self.day,
+            self.part,
+            json_string(&self.answer),
+            self.hash
+                .as_deref()
+                .map(json_string)
+                .unwrap_or("null".to_string()),
+            self.status,
+            self.accepted
+                .as_deref()
+                .map(json_string)
+                .unwrap_or("null".to_string()),
+            self.time.as_nanos()
+        )
+    }
+
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{}",
+            self.year,
+            self.day,
+            self.part,
+            csv_field(&self.answer),
+            self.hash.as_deref().unwrap_or_default(),
+            self.status,
+            self.accepted.as_deref().map(csv_field).unwrap_or_default(),
+            self.time.as_nanos()
+        )
+    }
+}
+
+// Renders results as a JSON array or a CSV table with a header. Text output
+// is printed by AoC as it goes, so there is nothing to render for it
+pub fn render(format: Format, results: &[PartResult]) -> String {
+    match format {
+        Format::Text => String::new(),
+        Format::Json => {
+            let records = results
+                .iter()
+                .map(|r| format!("  {}", r.to_json()))
+                .collect::<Vec<_>>();
+            if records.is_empty() {
+                "[]".to_string()
+            } else {
+                format!("[\n{}\n]", records.join(",\n"))
+            }
+        }
+        Format::Csv => std::iter::once(CSV_HEADER.to_string())
+            .chain(results.iter().map(|r| r.to_csv()))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
+pub(crate) fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+// Quotes a field as RFC 4180 says when it has commas, quotes or newlines
+pub(crate) fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
@@ -0,0 +1,150 @@
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::{AoC, Bench, Error, ExampleReport, Options, PartResult, Result, Solution};
+
+// Type erased view of a day so days with different answer types can live
+// together in a Registry. Days are shared with the threads `aoc run` solves
+// them on
+pub trait Puzzle: Send + Sync {
+    fn year(&self) -> u32;
+    fn day(&self) -> u32;
+    fn run(&self, options: &Options) -> Result<Vec<PartResult>>;
+    // Runs without printing, see AoC::run_captured
+    fn run_captured(&self, options: &Options) -> Result<(Vec<PartResult>, String)>;
+    fn bench(&self, runs: usize, options: &Options) -> Result<Bench>;
+    fn accept(&self, options: &Options) -> Result<PathBuf>;
+    // Runs the example `file` from the day's fixture manifest
+    fn test_fixture(&self, file: &str, options: &Options) -> Result<ExampleReport>;
+}
+
+pub struct Day<S: Solution> {
+    pub aoc: AoC<S::Part1, S::Part2>,
+    pub solution: S,
+}
+
+impl<S: Solution> Day<S> {
+    pub fn new(aoc: AoC<S::Part1, S::Part2>, solution: S) -> Self {
+        Self { aoc, solution }
+    }
+
+    fn aoc(&self, options: &Options) -> AoC<S::Part1, S::Part2> {
+        AoC {
+            options: options.clone(),
+            ..self.aoc
+        }
+    }
+}
+
+impl<S: Solution> Puzzle for Day<S>
+where
+    Self: Send + Sync,
+{
+    fn year(&self) -> u32 {
+        self.aoc.year
+    }
+
+    fn day(&self) -> u32 {
+        self.aoc.day
+    }
+
+    fn run(&self, options: &Options) -> Result<Vec<PartResult>> {
+        self.aoc(options).run(&self.solution)
+    }
+
+    fn run_captured(&self, options: &Options) -> Result<(Vec<PartResult>, String)> {
+        self.aoc(options).run_captured(&self.solution)
+    }
+
+    fn bench(&self, runs: usize, options: &Options) -> Result<Bench> {
+        self.aoc(options).bench(&self.solution, runs)
+    }
+
+    fn accept(&self, options: &Options) -> Result<PathBuf> {
+        self.aoc(options).accept(&self.solution)
+    }
+
+    fn test_fixture(&self, file: &str, options: &Options) -> Result<ExampleReport> {
+        let aoc = self.aoc(options);
+        let fixtures = aoc.fixtures()?;
+        match fixtures.iter().find(|f| f.file == file) {
+            Some(fixture) => aoc.test_fixture(&self.solution, fixture),
+            None => Err(Error::parse(
+                "Puzzle::test_fixture",
+                format!("day{}.fixtures.txt has no [{}]", self.aoc.day, file),
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DaySelection {
+    All,
+    Latest,
+    Range(RangeInclusive<u32>),
+}
+
+impl FromStr for DaySelection {
+    type Err = &'static str;
+
+    // Accepts `all`, `latest`, `7`, `3..=7` and `3..8`
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let bad_selection_msg =
+            "DaySelection::from_str::Expected `all`, `latest`, a day or a range of days";
+        let parse_day = |d: &str| d.trim().parse::<u32>().map_err(|_| bad_selection_msg);
+
+        match s.trim() {
+            "all" => Ok(Self::All),
+            "latest" => Ok(Self::Latest),
+            s => {
+                if let Some((start, end)) = s.split_once("..=") {
+                    Ok(Self::Range(parse_day(start)?..=parse_day(end)?))
+                } else if let Some((start, end)) = s.split_once("..") {
+                    let end = parse_day(end)?;
+                    if end == 0 {
+                        return Err(bad_selection_msg);
+                    }
+                    Ok(Self::Range(parse_day(start)?..=end - 1))
+                } else {
+                    let day = parse_day(s)?;
+                    Ok(Self::Range(day..=day))
+                }
+            }
+        }
+    }
+}
+
+pub struct Registry(Vec<Box<dyn Puzzle>>);
+
+impl Registry {
+    pub fn new(mut days: Vec<Box<dyn Puzzle>>) -> Self {
+        days.sort_by_key(|d| d.day());
+        Self(days)
+    }
+
+    pub fn get(&self, day: u32) -> Option<&dyn Puzzle> {
+        self.0.iter().find(|d| d.day() == day).map(|d| d.as_ref())
+    }
+
+    pub fn latest(&self) -> Option<&dyn Puzzle> {
+        self.0.last().map(|d| d.as_ref())
+    }
+
+    pub fn days(&self) -> Vec<u32> {
+        self.0.iter().map(|d| d.day()).collect()
+    }
+
+    pub fn select(&self, selection: &DaySelection) -> Vec<&dyn Puzzle> {
+        match selection {
+            DaySelection::All => self.0.iter().map(|d| d.as_ref()).collect(),
+            DaySelection::Latest => self.latest().into_iter().collect(),
+            DaySelection::Range(range) => self
+                .0
+                .iter()
+                .filter(|d| range.contains(&d.day()))
+                .map(|d| d.as_ref())
+                .collect(),
+        }
+    }
+}
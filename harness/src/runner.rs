@@ -0,0 +1,696 @@
+use std::any::Any;
+use std::path::Path;
+use std::process::ExitCode;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use crate::{
+    answer_hash, answer_key, baselines_file, fetch_input, fetch_leaderboard, git_revision,
+    leaderboard_file, migrate_inputs, render, report, scaffold, spoiler_safe_from_env,
+    submissions_file, submit_answer, Baselines, Bench, BenchTable, Config, DaySelection, Error,
+    Example, Fetched, Format, InputSource, Leaderboard, Options, Outcome, PartResult, Puzzle,
+    Registry, Result, Status, Submissions,
+};
+
+const USAGE: &str = "Usage:
+  aoc run [<days>] [--time] [--format text|json|csv] [--verify] [--timeout <seconds>]
+          [--spoiler-safe] [<input options>]
+  aoc accept [<days>]
+  aoc bench [<days>] [--runs <n>] [--save] [<input options>]
+  aoc compare [<days>] [--runs <n>] [--threshold <percent>] [--baseline <revision>]
+  aoc new <day> [--domain <module>]
+  aoc fetch <year> <day> [--force]
+  aoc submit <day> <part> [--spoiler-safe]
+  aoc leaderboard [<file>] [--id <id>] [--format text|json|csv]
+  aoc migrate
+
+<days> is a day, all, latest (the default) or a range like 3..=7.
+<input options> need a single day:
+  --input <file>      solve this instead of the real input, - is stdin
+  --example <file>    also check this example, - is stdin
+  --part1 <answer>    what the example should give for part 1
+  --part2 <answer>    and for part 2
+Every command takes --data-dir <dir> to read data files from <dir>/<year>
+instead of the workspace's data/<year>, as does the AOC_DATA_DIR environment
+variable. Without either, inputs are read from the vault first: AOC_VAULT, the
+config's vault, or ~/.local/share/aoc/inputs. migrate moves the inputs in the
+data directory there.
+
+run and submit --spoiler-safe, or setting AOC_SPOILER_SAFE, show hashes of the
+answers instead of the answers. They're keyed with answers.key next to the
+inputs, which accept creates, or AOC_ANSWER_KEY, and accepted answers are
+recorded as hashes. Without the key run --verify and --spoiler-safe fail, and a
+plain run doesn't check the accepted answers.
+
+fetch downloads a day's input with the session token from AOC_SESSION or the
+config file (AOC_CONFIG, or ~/.config/aoc/config), from AOC_BASE_URL or
+https://adventofcode.com. An input that's already there is kept unless
+--force is given. submit solves the real input and sends the part's answer
+there, unless earlier submissions in data/<year>/dayN.submissions.txt rule it
+out.
+
+leaderboard reports the private leaderboard exported to <file>, or downloads
+the one with --id or the config's leaderboard id at most every 15 minutes.";
+
+const DEFAULT_BENCH_RUNS: usize = 10;
+const DEFAULT_THRESHOLD_PERCENT: f64 = 10.0;
+// The main thread's usual stack, some days recurse deeply
+const DAY_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+enum Command {
+    // A day still running after `timeout` is given up on
+    Run {
+        timeout: Option<Duration>,
+    },
+    Accept,
+    Bench {
+        runs: usize,
+        save: bool,
+    },
+    Compare {
+        runs: usize,
+        threshold: f64,
+        baseline: Option<String>,
+    },
+    // Scaffolds a day, its domain module is `puzzle` unless named
+    New {
+        day: Option<u32>,
+        domain: String,
+    },
+    // Downloads an input, the first number is the year and the second the day
+    Fetch {
+        numbers: Vec<u32>,
+        force: bool,
+    },
+    // Sends an answer, the first number is the day and the second the part
+    Submit {
+        numbers: Vec<u32>,
+    },
+    // Reports an exported leaderboard, or downloads one when there's no file
+    Leaderboard {
+        file: Option<String>,
+        id: Option<String>,
+    },
+    // Moves the inputs out of the repository into the vault
+    Migrate,
+}
+
+// --example and the answers it should give, which can come in any order
+#[derive(Default)]
+struct ExampleArgs {
+    source: Option<InputSource>,
+    answer_1: Option<String>,
+    answer_2: Option<String>,
+}
+
+// How a day ended when run on its own thread
+enum Isolated {
+    // With the text it would have printed
+    Finished(Result<(Vec<PartResult>, String)>),
+    Panicked(String),
+    TimedOut,
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "no message".to_string()
+    }
+}
+
+// A day that panics or runs past the timeout can't take the others down with
+// it. A timed out thread can't be stopped, so it keeps running in the
+// background until the process exits. Its output comes back with its results
+// so an abandoned day never prints
+fn run_isolated(
+    day: &'static dyn Puzzle,
+    options: &Options,
+    timeout: Option<Duration>,
+) -> Isolated {
+    let (sender, receiver) = mpsc::channel();
+    let options = options.clone();
+    let spawned = thread::Builder::new()
+        .name(format!("day{}", day.day()))
+        .stack_size(DAY_STACK_SIZE)
+        .spawn(move || {
+            // The receiver is gone if the day timed out
+            let _ = sender.send(day.run_captured(&options));
+        });
+    let handle = match spawned {
+        Ok(handle) => handle,
+        Err(e) => return Isolated::Panicked(format!("couldn't start its thread: {}", e)),
+    };
+
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+    match received {
+        Ok(result) => Isolated::Finished(result),
+        Err(RecvTimeoutError::Timeout) => Isolated::TimedOut,
+        // The sender is only dropped without sending when the day panicked
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(payload) => Isolated::Panicked(panic_message(&*payload)),
+            Ok(()) => Isolated::Panicked("stopped without an answer".to_string()),
+        },
+    }
+}
+
+// Solves the real input and sends the answer to `part`. Whether it was right.
+// When spoiler-safe, answers are only shown as their hashes
+fn submit(day: &dyn Puzzle, part: u8, options: &Options) -> Result<bool> {
+    // The site needs the answer itself, not its hash
+    let (results, text) = day.run_captured(&Options {
+        spoiler_safe: false,
+        ..options.clone()
+    })?;
+    let key = if options.spoiler_safe {
+        Some(answer_key(day.year(), options.data_dir.as_deref(), true)?)
+    } else {
+        print!("{}", text);
+        None
+    };
+    let show = |answer: &str| match &key {
+        Some(key) => answer_hash(key, answer),
+        None => answer.to_string(),
+    };
+    let Some(result) = results.iter().find(|r| r.part == part) else {
+        return Ok(false);
+    };
+    let refuse = |message: String| Err(Error::Refused { message });
+    match result.status {
+        Status::Failed => {
+            return Err(Error::TestMismatch {
+                day: day.day(),
+                parts: vec![part],
+            })
+        }
+        Status::Unsolved => return refuse(format!("Part {} isn't solved yet", part)),
+        _ => {}
+    }
+    if result.answer.contains('\n') {
+        return refuse(format!(
+            "Part {}'s answer is a drawing, submit the letters it shows on the website",
+            part
+        ));
+    }
+
+    let config = Config::load()?;
+    let mut submissions = Submissions::load(submissions_file(
+        day.year(),
+        day.day(),
+        options.data_dir.as_deref(),
+    ))?;
+    let submission = submit_answer(
+        &config,
+        &mut submissions,
+        day.year(),
+        day.day(),
+        part,
+        &result.answer,
+        &show,
+    )?;
+
+    println!(
+        "Day {} part {}: {} was {}",
+        day.day(),
+        part,
+        show(&submission.answer),
+        submission.outcome.describe()
+    );
+    if !submission.wait.is_zero() {
+        println!(
+            "Wait {}s before submitting again",
+            submission.wait.as_secs()
+        );
+    }
+    if submission.outcome == Outcome::Right {
+        println!(
+            "Record it for `run --verify` with `aoc accept {}`",
+            day.day()
+        );
+    }
+    Ok(submission.outcome == Outcome::Right)
+}
+
+fn usage_error(message: &str) -> ExitCode {
+    eprintln!("{}\n{}", message, USAGE);
+    ExitCode::FAILURE
+}
+
+fn bench(days: &[&dyn Puzzle], runs: usize, options: &Options) -> Result<Vec<Bench>> {
+    let mut benches = Vec::new();
+    for day in days {
+        let bench = day.bench(runs, options)?;
+        println!("{}\n", bench);
+        benches.push(bench);
+    }
+    if benches.len() > 1 {
+        println!("{}\n", BenchTable(&benches));
+    }
+    Ok(benches)
+}
+
+// Whether every answer is still the accepted one. What changed goes to
+// stderr so it doesn't mix with JSON or CSV output
+fn verify_report(results: &[PartResult]) -> bool {
+    let changed: Vec<_> = results.iter().filter(|r| r.changed()).collect();
+    let unrecorded = results
+        .iter()
+        .filter(|r| r.accepted.is_none() && r.status != Status::Unsolved)
+        .count();
+
+    // Only hashes are recorded, so that's all there is to compare
+    for result in &changed {
+        eprintln!(
+            "Day {} part {} changed, its hash was {} and is now {}",
+            result.day,
+            result.part,
+            result.accepted.as_deref().unwrap_or_default(),
+            result.hash.as_deref().unwrap_or("unsolved")
+        );
+    }
+    eprintln!(
+        "Checked {} answers against the accepted ones: {} changed, {} not recorded yet",
+        results.iter().filter(|r| r.accepted.is_some()).count(),
+        changed.len(),
+        unrecorded
+    );
+
+    changed.is_empty()
+}
+
+// The `aoc` command of a year's crate, which passes its directory for `aoc new`
+// and its days
+pub fn runner(year: u32, crate_dir: &Path, registry: Registry) -> ExitCode {
+    // Days are run on threads that can outlive a timeout
+    let registry: &'static Registry = Box::leak(Box::new(registry));
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut args = args.iter().map(|a| a.as_str());
+
+    let mut command = match args.next() {
+        Some("run") => Command::Run { timeout: None },
+        Some("accept") => Command::Accept,
+        Some("bench") => Command::Bench {
+            runs: DEFAULT_BENCH_RUNS,
+            save: false,
+        },
+        Some("compare") => Command::Compare {
+            runs: DEFAULT_BENCH_RUNS,
+            threshold: DEFAULT_THRESHOLD_PERCENT,
+            baseline: None,
+        },
+        Some("new") => Command::New {
+            day: None,
+            domain: "puzzle".to_string(),
+        },
+        Some("fetch") => Command::Fetch {
+            numbers: Vec::new(),
+            force: false,
+        },
+        Some("submit") => Command::Submit {
+            numbers: Vec::new(),
+        },
+        Some("leaderboard") => Command::Leaderboard {
+            file: None,
+            id: None,
+        },
+        Some("migrate") => Command::Migrate,
+        _ => return usage_error("Expected a command"),
+    };
+    let mut selection = DaySelection::Latest;
+    let mut options = Options {
+        spoiler_safe: spoiler_safe_from_env(),
+        ..Options::default()
+    };
+    let mut example = ExampleArgs::default();
+
+    while let Some(arg) = args.next() {
+        match (&mut command, arg) {
+            (_, "--data-dir") => match args.next() {
+                Some(dir) => options.data_dir = Some(dir.into()),
+                None => return usage_error("--data-dir needs a directory"),
+            },
+            (Command::Run { .. } | Command::Bench { .. }, "--input") => match args.next() {
+                Some(file) => options.input = Some(file.into()),
+                None => return usage_error("--input needs a file"),
+            },
+            (Command::Run { .. } | Command::Bench { .. }, "--example") => match args.next() {
+                Some(file) => example.source = Some(file.into()),
+                None => return usage_error("--example needs a file"),
+            },
+            (Command::Run { .. } | Command::Bench { .. }, "--part1") => match args.next() {
+                Some(answer) => example.answer_1 = Some(answer.to_string()),
+                None => return usage_error("--part1 needs an answer"),
+            },
+            (Command::Run { .. } | Command::Bench { .. }, "--part2") => match args.next() {
+                Some(answer) => example.answer_2 = Some(answer.to_string()),
+                None => return usage_error("--part2 needs an answer"),
+            },
+            (Command::Run { .. }, "--time") => options.timed = true,
+            (Command::Run { .. }, "--verify") => options.verify = true,
+            (Command::Run { .. } | Command::Submit { .. }, "--spoiler-safe") => {
+                options.spoiler_safe = true
+            }
+            (Command::Run { timeout, .. }, "--timeout") => {
+                *timeout = match args.next().map(|n| n.parse::<f64>()) {
+                    Some(Ok(n)) if n > 0.0 && n.is_finite() => Some(Duration::from_secs_f64(n)),
+                    _ => return usage_error("--timeout needs a number of seconds above 0"),
+                }
+            }
+            (Command::Run { .. } | Command::Leaderboard { .. }, "--format") => {
+                options.format = match args.next().map(|f| f.parse()) {
+                    Some(Ok(v)) => v,
+                    Some(Err(e)) => return usage_error(e),
+                    None => return usage_error("--format needs a format"),
+                }
+            }
+            (Command::Bench { runs, .. } | Command::Compare { runs, .. }, "--runs") => {
+                *runs = match args.next().map(|n| n.parse()) {
+                    Some(Ok(n)) if n > 0 => n,
+                    _ => return usage_error("--runs needs a number of runs above 0"),
+                }
+            }
+            (Command::Bench { save, .. }, "--save") => *save = true,
+            (Command::Compare { threshold, .. }, "--threshold") => {
+                *threshold = match args.next().map(|n| n.parse::<f64>()) {
+                    Some(Ok(n)) if n >= 0.0 && n.is_finite() => n,
+                    _ => return usage_error("--threshold needs a percentage of 0 or more"),
+                }
+            }
+            (Command::Compare { baseline, .. }, "--baseline") => match args.next() {
+                Some(revision) => *baseline = Some(revision.to_string()),
+                None => return usage_error("--baseline needs a revision"),
+            },
+            (Command::New { domain, .. }, "--domain") => match args.next() {
+                Some(name) => *domain = name.to_string(),
+                None => return usage_error("--domain needs a module name"),
+            },
+            (Command::New { day, .. }, value) if !value.starts_with("--") => {
+                *day = match value.parse() {
+                    Ok(v) => Some(v),
+                    Err(_) => return usage_error("new needs a day number"),
+                }
+            }
+            (Command::Fetch { force, .. }, "--force") => *force = true,
+            (Command::Fetch { numbers, .. }, value) if !value.starts_with("--") => {
+                match value.parse() {
+                    Ok(n) if numbers.len() < 2 => numbers.push(n),
+                    _ => return usage_error("fetch needs a year and a day"),
+                }
+            }
+            (Command::Submit { numbers }, value) if !value.starts_with("--") => {
+                match value.parse() {
+                    Ok(n) if numbers.len() < 2 => numbers.push(n),
+                    _ => return usage_error("submit needs a day and a part"),
+                }
+            }
+            (Command::Leaderboard { id, .. }, "--id") => match args.next() {
+                Some(value) => *id = Some(value.to_string()),
+                None => return usage_error("--id needs a leaderboard id"),
+            },
+            (Command::Leaderboard { file, .. }, value) if !value.starts_with("--") => {
+                *file = Some(value.to_string())
+            }
+            (_, flag) if flag.starts_with("--") => {
+                return usage_error(&format!("Unknown option {}", flag))
+            }
+            (_, days) => {
+                selection = match days.parse() {
+                    Ok(v) => v,
+                    Err(e) => return usage_error(e),
+                }
+            }
+        }
+    }
+
+    options.example = match example {
+        ExampleArgs {
+            source: Some(source),
+            answer_1,
+            answer_2,
+        } => Some(Example {
+            source,
+            answer_1,
+            answer_2,
+        }),
+        ExampleArgs {
+            source: None,
+            answer_1: None,
+            answer_2: None,
+        } => None,
+        _ => return usage_error("--part1 and --part2 are the answers to an --example"),
+    };
+    let stdin = InputSource::Stdin;
+    if options.input == Some(stdin.clone())
+        && options.example.as_ref().is_some_and(|e| e.source == stdin)
+    {
+        return usage_error("Only one of --input and --example can read stdin");
+    }
+
+    if let Command::New { day, domain } = &command {
+        let Some(day) = *day else {
+            return usage_error("new needs a day number");
+        };
+        return match scaffold(year, day, domain, crate_dir, options.data_dir.as_deref()) {
+            Ok(created) => {
+                println!("Created day {}:", day);
+                for path in created {
+                    println!("  {}", path.display());
+                }
+                ExitCode::SUCCESS
+            }
+            Err(e) => report(Err(e)),
+        };
+    }
+
+    if let Command::Fetch { numbers, force } = &command {
+        let &[year, day] = numbers.as_slice() else {
+            return usage_error("fetch needs a year and a day");
+        };
+        let fetched = Config::load().and_then(|config| {
+            fetch_input(&config, year, day, options.data_dir.as_deref(), *force)
+        });
+        return match fetched {
+            Ok(Fetched::Downloaded(path)) => {
+                println!("Downloaded {}", path.display());
+                ExitCode::SUCCESS
+            }
+            Ok(Fetched::Cached(path)) => {
+                println!(
+                    "Already have {}, use --force to download it again",
+                    path.display()
+                );
+                ExitCode::SUCCESS
+            }
+            Err(e) => report(Err(e)),
+        };
+    }
+
+    if let Command::Leaderboard { file, id } = &command {
+        let leaderboard = match file {
+            Some(file) => Leaderboard::load(Path::new(file)),
+            None => Config::load().and_then(|config| {
+                let Some(id) = id.as_ref().or(config.leaderboard.as_ref()) else {
+                    return Err(Error::Config {
+                        message: "No leaderboard id. Give it with --id or add `leaderboard = <id>` to the config file".to_string(),
+                    });
+                };
+                let path = leaderboard_file(year, id, options.data_dir.as_deref());
+                Leaderboard::parse(&fetch_leaderboard(&config, year, id, &path)?)
+            }),
+        };
+        return match leaderboard {
+            Ok(leaderboard) => {
+                println!("{}", leaderboard.render(options.format));
+                ExitCode::SUCCESS
+            }
+            Err(e) => report(Err(e)),
+        };
+    }
+
+    if let Command::Migrate = command {
+        return match migrate_inputs(options.data_dir.as_deref()) {
+            Ok(moves) if moves.is_empty() => {
+                println!("There are no inputs to move");
+                ExitCode::SUCCESS
+            }
+            Ok(moves) => {
+                for (from, to) in &moves {
+                    println!("Moved {} to {}", from.display(), to.display());
+                }
+                println!("Commit the removed inputs to take them out of the repository");
+                ExitCode::SUCCESS
+            }
+            Err(e) => report(Err(e)),
+        };
+    }
+
+    if let Command::Submit { numbers } = &command {
+        let &[day, 1 | 2] = numbers.as_slice() else {
+            return usage_error("submit needs a day and a part, 1 or 2");
+        };
+        selection = DaySelection::Range(day..=day);
+    }
+
+    let days = registry.select(&selection);
+    if days.is_empty() {
+        eprintln!(
+            "No days match that selection. Available days: {:?}",
+            registry.days()
+        );
+        return ExitCode::FAILURE;
+    }
+    if (options.input.is_some() || options.example.is_some()) && days.len() != 1 {
+        return usage_error("--input and --example can only be used with a single day");
+    }
+    if options.input.is_some() && matches!(command, Command::Bench { save: true, .. }) {
+        return usage_error("Baselines are for the real input, --save can't be used with --input");
+    }
+
+    match command {
+        Command::Run { timeout } => {
+            // A day that fails is reported and the next one still runs
+            let mut results = Vec::new();
+            let mut failures = Vec::new();
+            let mut timed_out = Vec::new();
+            for &day in &days {
+                match run_isolated(day, &options, timeout) {
+                    Isolated::Finished(Ok((day_results, text))) => {
+                        print!("{}", text);
+                        let wrong: Vec<String> = day_results
+                            .iter()
+                            .filter(|r| r.status == Status::Failed)
+                            .map(|r| r.part.to_string())
+                            .collect();
+                        if !wrong.is_empty() {
+                            failures.push(format!(
+                                "day {}: got examples of part {} wrong",
+                                day.day(),
+                                wrong.join(" and ")
+                            ));
+                        }
+                        results.extend(day_results);
+                    }
+                    Isolated::Finished(Err(e)) => {
+                        let message = e.to_string().lines().next().unwrap_or_default().to_string();
+                        report(Err(e));
+                        failures.push(format!("day {}: {}", day.day(), message));
+                    }
+                    // The panic hook has already printed where it happened
+                    Isolated::Panicked(message) => {
+                        failures.push(format!("day {}: panicked: {}", day.day(), message))
+                    }
+                    Isolated::TimedOut => {
+                        let timeout = timeout.unwrap_or_default();
+                        eprintln!("Day {} didn't finish in {:?}", day.day(), timeout);
+                        timed_out.push(format!("day {}: timed out after {:?}", day.day(), timeout));
+                    }
+                }
+            }
+            if options.format != Format::Text {
+                println!("{}", render(options.format, &results));
+            }
+
+            // Goes to stderr with the rest of the failures
+            if days.len() > 1 || !failures.is_empty() || !timed_out.is_empty() {
+                eprintln!(
+                    "\n{} passed, {} failed, {} timed out",
+                    days.len() - failures.len() - timed_out.len(),
+                    failures.len(),
+                    timed_out.len()
+                );
+                for line in failures.iter().chain(&timed_out) {
+                    eprintln!("  {}", line);
+                }
+            }
+            let verified = !options.verify || verify_report(&results);
+            if failures.is_empty() && timed_out.is_empty() && verified {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Command::Accept => {
+            for day in days {
+                match day.accept(&options) {
+                    Ok(path) => println!(
+                        "Recorded the accepted answers for day {} in {}",
+                        day.day(),
+                        path.display()
+                    ),
+                    Err(e) => return report(Err(e)),
+                }
+            }
+            ExitCode::SUCCESS
+        }
+        Command::Bench { runs, save } => report(bench(&days, runs, &options).and_then(|benches| {
+            if save {
+                let revision = git_revision();
+                Baselines::load(baselines_file(year))?.save(&benches, &revision)?;
+                println!("Saved as the baseline for {}", revision);
+            }
+            Ok(())
+        })),
+        Command::Submit { numbers } => match submit(days[0], numbers[1] as u8, &options) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(e) => report(Err(e)),
+        },
+        Command::New { .. }
+        | Command::Fetch { .. }
+        | Command::Leaderboard { .. }
+        | Command::Migrate => {
+            unreachable!("only commands on days are left once days are selected")
+        }
+        Command::Compare {
+            runs,
+            threshold,
+            baseline,
+        } => {
+            let result = Baselines::load(baselines_file(year))
+                .and_then(|baselines| Ok((baselines, bench(&days, runs, &options)?)));
+            let (baselines, benches) = match result {
+                Ok(v) => v,
+                Err(e) => return report(Err(e)),
+            };
+
+            let mut regressed_days = Vec::new();
+            println!(
+                "  {:<6} {:<6} {:>10} {:>10} {:>9}",
+                "Day", "Stage", "Baseline", "Now", "Change"
+            );
+            for bench in &benches {
+                let comparisons = baselines.compare(bench, baseline.as_deref());
+                if comparisons.is_empty() {
+                    println!(
+                        "  {:<6} no baseline yet, save one with `bench --save`",
+                        bench.day
+                    );
+                }
+                for comparison in &comparisons {
+                    let regressed = comparison.regressed(threshold);
+                    println!("{}{}", comparison, if regressed { "  SLOWER" } else { "" });
+                    if regressed && !regressed_days.contains(&bench.day) {
+                        regressed_days.push(bench.day);
+                    }
+                }
+            }
+
+            if regressed_days.is_empty() {
+                println!("\nNo day got more than {}% slower", threshold);
+                ExitCode::SUCCESS
+            } else {
+                eprintln!(
+                    "\nDays {:?} got more than {}% slower than their baseline",
+                    regressed_days, threshold
+                );
+                ExitCode::FAILURE
+            }
+        }
+    }
+}
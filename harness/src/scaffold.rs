@@ -0,0 +1,261 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::{data_dirs, Error, Input, Result};
+
+const DAYS_MOD: &str = "src/days/mod.rs";
+
+// Creates what a new day needs in the crate of its year: its module under
+// `src/days` with a `mod <domain>` to fill in, a bin, an empty example and a
+// fixture manifest listing the example. The day is added to the registry in
+// `src/days/mod.rs`. The input is left to `aoc fetch`, it belongs in the
+// vault. Nothing is written if any of the files already exist. Returns the
+// files it created
+pub fn scaffold(
+    year: u32,
+    day: u32,
+    domain: &str,
+    crate_dir: &Path,
+    data_dir: Option<&Path>,
+) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::Argument {
+            message: format!("There is no day {}, days go from 1 to 25", day),
+        });
+    }
+    if domain.is_empty()
+        || domain.starts_with(|c: char| c.is_ascii_digit())
+        || !domain
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        return Err(Error::Argument {
+            message: format!("The domain module `{}` should be snake_case", domain),
+        });
+    }
+
+    let data_dir = data_dirs(year, data_dir).remove(0);
+    let days_mod = crate_dir.join(DAYS_MOD);
+    let days_mod_content = std::fs::read_to_string(&days_mod).map_err(|source| Error::Io {
+        path: days_mod.clone(),
+        source,
+    })?;
+
+    let files = [
+        (
+            crate_dir.join(format!("src/days/day{}.rs", day)),
+            day_module(day, domain),
+        ),
+        (
+            crate_dir.join(format!("src/bin/day{}.rs", day)),
+            day_bin(day),
+        ),
+        (data_dir.join(format!("day{}.test.txt", day)), String::new()),
+        (
+            data_dir.join(format!("day{}.fixtures.txt", day)),
+            format!("[day{}.test.txt]\n", day),
+        ),
+    ];
+
+    let mut existing: Vec<PathBuf> = files
+        .iter()
+        .map(|(path, _)| path.clone())
+        .filter(|path| path.exists())
+        .collect();
+    if days_mod_content.contains(&format!("pub mod day{};", day)) {
+        existing.push(days_mod.clone());
+    }
+    if !existing.is_empty() {
+        return Err(Error::Exists { paths: existing });
+    }
+    let registered = register(&days_mod_content, day)?;
+
+    let mut created = Vec::new();
+    for (path, content) in files {
+        write_new(&path, &content)?;
+        created.push(path);
+    }
+    std::fs::write(&days_mod, registered).map_err(|source| Error::Io {
+        path: days_mod.clone(),
+        source,
+    })?;
+    created.push(days_mod);
+
+    Ok(created)
+}
+
+fn write_new(path: &Path, content: &str) -> Result<()> {
+    let io_error = |source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .map_err(io_error)
+}
+
+// Adds `pub mod dayN;` where rustfmt would sort it and the day at the end
+// of the registry, which should end with a `])` line
+fn register(days_mod: &str, day: u32) -> Result<String> {
+    let module = format!("pub mod day{};", day);
+    let entry = format!(
+        "        Box::new(Day::new(day{}::aoc(), day{}::Solver)),",
+        day, day
+    );
+
+    let mut lines: Vec<String> = days_mod.lines().map(|l| l.to_string()).collect();
+    let mods: Vec<usize> = (0..lines.len())
+        .filter(|i| lines[*i].starts_with("pub mod day"))
+        .collect();
+    // rustfmt sorts by name, so day1 goes before day13
+    let name = |line: &str| line.trim_end_matches(';').to_string();
+    let module_at = mods
+        .iter()
+        .find(|i| name(&lines[**i]) > name(&module))
+        .copied()
+        .unwrap_or_else(|| mods.last().map_or(0, |i| i + 1));
+    lines.insert(module_at, module);
+
+    let Some(end) = lines.iter().rposition(|l| l.trim() == "])") else {
+        return Err(Input::from_content(days_mod).error_at_end(
+            "register",
+            format!("{} has no registry to add day {} to", DAYS_MOD, day),
+            "`Registry::new(vec![` and its days, ending with `])`",
+        ));
+    };
+    lines.insert(end, entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn type_name(domain: &str) -> String {
+    domain
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn day_module(day: u32, domain: &str) -> String {
+    let name = type_name(domain);
+    format!(
+        r#"use crate::{{Answer, AoC, Input, Result, Solution, Unsolved, YEAR}};
+
+pub mod {domain} {{
+    use crate::{{Input, Result}};
+
+    // TODO: the puzzle's model
+    #[derive(Debug)]
+    pub struct {name}(pub Vec<String>);
+
+    impl {name} {{
+        pub fn new(input: &Input) -> Result<Self> {{
+            Ok({name}(input.to_vec()))
+        }}
+    }}
+}}
+
+pub struct Solver;
+
+impl Solution for Solver {{
+    type Parsed = {domain}::{name};
+    type Part1 = Unsolved;
+    type Part2 = Unsolved;
+
+    fn parse(&self, input: Input) -> Result<Self::Parsed> {{
+        {domain}::{name}::new(&input)
+    }}
+
+    fn part1(&self, _{domain}: &Self::Parsed) -> Result<Unsolved> {{
+        Ok(Unsolved)
+    }}
+
+    fn part2(&self, _{domain}: &Self::Parsed) -> Result<Unsolved> {{
+        Ok(Unsolved)
+    }}
+}}
+
+pub fn aoc() -> AoC<Unsolved, Unsolved> {{
+    AoC::new(YEAR, {day})
+}}
+
+pub fn get_answer(input: Input) -> Result<Answer<Unsolved, Unsolved>> {{
+    Solver.solve(input)
+}}
+"#
+    )
+}
+
+fn day_bin(day: u32) -> String {
+    format!(
+        "use aoc::days::day{day};
+
+fn main() -> std::process::ExitCode {{
+    aoc::report(day{day}::aoc().compute(&day{day}::Solver))
+}}
+"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS_MOD_CONTENT: &str = "use crate::{Day, Registry};
+
+pub mod day1;
+pub mod day2;
+
+pub fn registry() -> Registry {
+    Registry::new(vec![
+        Box::new(Day::new(day1::aoc(), day1::Solver)),
+        Box::new(Day::new(day2::aoc(), day2::Solver)),
+    ])
+}
+";
+
+    #[test]
+    fn registers_the_day_where_rustfmt_would() {
+        let registered = register(DAYS_MOD_CONTENT, 13).unwrap();
+        assert!(registered.contains("pub mod day1;\npub mod day13;\npub mod day2;\n"));
+        assert!(registered.contains(
+            "day2::Solver)),\n        Box::new(Day::new(day13::aoc(), day13::Solver)),\n    ])"
+        ));
+    }
+
+    #[test]
+    fn registry_without_an_end_is_an_error() {
+        let days_mod = DAYS_MOD_CONTENT.replace("    ])\n", "");
+        let error = register(&days_mod, 3).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("register::src/days/mod.rs has no registry to add day 3 to"));
+    }
+
+    #[test]
+    fn nothing_is_written_without_a_registry() {
+        let crate_dir = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let data_dir = crate_dir.join("data");
+        std::fs::create_dir_all(crate_dir.join("src/days")).unwrap();
+        std::fs::write(crate_dir.join(DAYS_MOD), "pub mod day1;\n").unwrap();
+
+        let result = scaffold(2022, 3, "puzzle", &crate_dir, Some(&data_dir));
+        let day_module = crate_dir.join("src/days/day3.rs").exists();
+        std::fs::remove_dir_all(&crate_dir).unwrap();
+
+        assert!(result.is_err());
+        assert!(!day_module);
+        assert!(!data_dir.exists());
+    }
+}
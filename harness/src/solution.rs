@@ -0,0 +1,53 @@
+use std::fmt::Display;
+
+use crate::{Answer, Input, InputStream, Normalization, PartAnswer, Result};
+
+// A day split into its stages. `parse` turns the input into the day's model
+// once and both parts share it, so each stage can be run and timed on its own
+pub trait Solution {
+    type Parsed;
+    type Part1: PartAnswer;
+    type Part2: PartAnswer;
+    // Days that override parse_stream set this so their real input is
+    // streamed too. Reading is timed as part of parsing then
+    const STREAMS: bool = false;
+    // How the input is cleaned up before it's parsed, for the real input and
+    // the examples alike
+    const NORMALIZATION: Normalization = Normalization::DEFAULT;
+
+    fn parse(&self, input: Input) -> Result<Self::Parsed>;
+    // Parses the input as it's read, without holding every line in memory.
+    // Single pass days can override it, the rest read everything first
+    fn parse_stream(&self, input: &mut InputStream) -> Result<Self::Parsed> {
+        self.parse(input.collect()?)
+    }
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Self::Part1>;
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Self::Part2>;
+
+    fn solve(&self, input: Input) -> Result<Answer<Self::Part1, Self::Part2>> {
+        let parsed = self.parse(input)?;
+        Ok(Answer(self.part1(&parsed)?, self.part2(&parsed)?))
+    }
+}
+
+// Runs a day written as a single `get_answer` function. The whole answer is
+// computed while parsing and the parts just hand back their half of it
+pub struct FnSolution<T: Display, B: Display>(pub fn(Input) -> Result<Answer<T, B>>);
+
+impl<T: PartAnswer + Clone, B: PartAnswer + Clone> Solution for FnSolution<T, B> {
+    type Parsed = Answer<T, B>;
+    type Part1 = T;
+    type Part2 = B;
+
+    fn parse(&self, input: Input) -> Result<Self::Parsed> {
+        (self.0)(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> Result<T> {
+        Ok(parsed.0.clone())
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> Result<B> {
+        Ok(parsed.1.clone())
+    }
+}
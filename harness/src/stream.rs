@@ -0,0 +1,382 @@
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Cursor};
+use std::path::{Path, PathBuf};
+
+use crate::{Error, Input, Result};
+
+const BOM: &[u8] = b"\xef\xbb\xbf";
+
+// What to do with whitespace at the ends of lines. Leading whitespace is
+// always kept, day 5's drawing is aligned with it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Whitespace {
+    Keep,
+    TrimEnd,
+}
+
+// How an input is cleaned up before a day sees it. Inputs saved on Windows or
+// copied from a browser can have a BOM, `\r\n` line endings or trailing spaces
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalization {
+    pub strip_bom: bool,
+    pub strip_cr: bool,
+    pub whitespace: Whitespace,
+    // Drop the blank lines after the last one with something on it
+    pub drop_trailing_blank_lines: bool,
+}
+
+impl Normalization {
+    pub const DEFAULT: Self = Self {
+        strip_bom: true,
+        strip_cr: true,
+        whitespace: Whitespace::TrimEnd,
+        drop_trailing_blank_lines: true,
+    };
+
+    // The input exactly as it is, apart from the `\n` line endings
+    pub const NONE: Self = Self {
+        strip_bom: false,
+        strip_cr: false,
+        whitespace: Whitespace::Keep,
+        drop_trailing_blank_lines: false,
+    };
+}
+
+impl Default for Normalization {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+// What normalising an input changed, so it can be warned about
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NormalizationReport {
+    pub bom: bool,
+    pub cr_lines: usize,
+    pub trimmed_lines: usize,
+    pub trailing_blank_lines: usize,
+}
+
+impl NormalizationReport {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Display for NormalizationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut changes = Vec::new();
+        if self.bom {
+            changes.push("removed a byte order mark".to_string());
+        }
+        if self.cr_lines > 0 {
+            changes.push(format!("removed \\r from {} lines", self.cr_lines));
+        }
+        if self.trimmed_lines > 0 {
+            changes.push(format!(
+                "trimmed trailing whitespace on {} lines",
+                self.trimmed_lines
+            ));
+        }
+        if self.trailing_blank_lines > 0 {
+            changes.push(format!(
+                "dropped {} blank lines at the end",
+                self.trailing_blank_lines
+            ));
+        }
+        write!(f, "{}", changes.join(", "))
+    }
+}
+
+// A puzzle input that is read as it's parsed instead of being held in memory
+// line by line, for days that only need a single pass over it. See
+// Solution::parse_stream
+pub struct InputStream {
+    // Where it's read from, for io errors. `-` is stdin
+    path: PathBuf,
+    reader: Box<dyn BufRead>,
+    normalization: Normalization,
+    report: NormalizationReport,
+    // Index of the next line read
+    index: usize,
+    // Blank lines held back until it's known whether they are at the end,
+    // and the line that showed they weren't
+    blank_lines: usize,
+    held: Option<(usize, String)>,
+}
+
+impl InputStream {
+    pub fn new(path: impl Into<PathBuf>, reader: impl BufRead + 'static) -> Self {
+        Self {
+            path: path.into(),
+            reader: Box::new(reader),
+            normalization: Normalization::NONE,
+            report: NormalizationReport::default(),
+            index: 0,
+            blank_lines: 0,
+            held: None,
+        }
+    }
+
+    pub fn open(path: &Path) -> Result<Self> {
+        match File::open(path) {
+            Ok(file) => Ok(Self::new(path, BufReader::new(file))),
+            Err(source) => Err(Error::Io {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    // Cleans up the lines as they're read. The BOM is also skipped for
+    // `reader`, the rest only applies to `lines` and `collect`
+    pub fn normalized(mut self, normalization: Normalization) -> Result<Self> {
+        self.normalization = normalization;
+        if normalization.strip_bom {
+            let has_bom = match self.reader.fill_buf() {
+                Ok(buffer) => buffer.starts_with(BOM),
+                Err(e) => return Err(self.io_error(e)),
+            };
+            if has_bom {
+                self.reader.consume(BOM.len());
+                self.report.bom = true;
+            }
+        }
+        Ok(self)
+    }
+
+    pub fn report(&self) -> NormalizationReport {
+        self.report
+    }
+
+    // The lines with their 0-based index, which is what Error::on_line takes
+    pub fn lines(&mut self) -> Lines<'_> {
+        Lines { stream: self }
+    }
+
+    // The raw bytes, for days that don't think in lines. They can clean up
+    // the ends of lines with clean_line_end
+    pub fn reader(&mut self) -> &mut dyn BufRead {
+        &mut self.reader
+    }
+
+    // For days reading `reader`: whether the `whitespace` bytes a line ends
+    // with are cleaned up like `lines` would, recording it in the report.
+    // The last of them is a `\r` if `cr`
+    pub fn clean_line_end(&mut self, whitespace: usize, cr: bool) -> bool {
+        let mut left = whitespace;
+        if self.normalization.strip_cr && cr {
+            left -= 1;
+            self.report.cr_lines += 1;
+        }
+        if left > 0 && self.normalization.whitespace == Whitespace::TrimEnd {
+            left = 0;
+            self.report.trimmed_lines += 1;
+        }
+        left == 0
+    }
+
+    pub fn io_error(&self, source: std::io::Error) -> Error {
+        Error::Io {
+            path: self.path.clone(),
+            source,
+        }
+    }
+
+    // Reads the lines that are left into memory
+    pub fn collect(&mut self) -> Result<Input> {
+        let lines = self
+            .lines()
+            .map(|line| line.map(|(_, line)| line))
+            .collect::<Result<_>>()?;
+        Ok(Input::new(lines))
+    }
+
+    fn next_line(&mut self) -> Option<Result<(usize, String)>> {
+        if let Some((index, _)) = &self.held {
+            if self.blank_lines > 0 {
+                let blank_index = index - self.blank_lines;
+                self.blank_lines -= 1;
+                return Some(Ok((blank_index, String::new())));
+            }
+            return self.held.take().map(Ok);
+        }
+
+        loop {
+            let (index, line) = match self.read_line() {
+                Some(Ok(v)) => v,
+                Some(Err(e)) => return Some(Err(e)),
+                None => {
+                    self.report.trailing_blank_lines += self.blank_lines;
+                    self.blank_lines = 0;
+                    return None;
+                }
+            };
+            if line.is_empty() && self.normalization.drop_trailing_blank_lines {
+                self.blank_lines += 1;
+            } else if self.blank_lines > 0 {
+                self.held = Some((index, line));
+                return self.next_line();
+            } else {
+                return Some(Ok((index, line)));
+            }
+        }
+    }
+
+    fn read_line(&mut self) -> Option<Result<(usize, String)>> {
+        let mut line = String::new();
+        match self.reader.read_line(&mut line) {
+            Ok(0) => return None,
+            Ok(_) => (),
+            Err(e) => return Some(Err(self.io_error(e))),
+        }
+
+        if line.ends_with('\n') {
+            line.pop();
+        }
+        if self.normalization.strip_cr && line.ends_with('\r') {
+            line.pop();
+            self.report.cr_lines += 1;
+        }
+        if self.normalization.whitespace == Whitespace::TrimEnd {
+            let len = line.trim_end().len();
+            if len != line.len() {
+                line.truncate(len);
+                self.report.trimmed_lines += 1;
+            }
+        }
+
+        self.index += 1;
+        Some(Ok((self.index - 1, line)))
+    }
+}
+
+impl From<Input> for InputStream {
+    fn from(input: Input) -> Self {
+        let content: String = input.iter().map(|line| format!("{}\n", line)).collect();
+        Self::new("", Cursor::new(content))
+    }
+}
+
+pub struct Lines<'a> {
+    stream: &'a mut InputStream,
+}
+
+impl Iterator for Lines<'_> {
+    type Item = Result<(usize, String)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.stream.next_line()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stream(content: &str, normalization: Normalization) -> InputStream {
+        InputStream::new("test", Cursor::new(content.to_string()))
+            .normalized(normalization)
+            .unwrap()
+    }
+
+    // The lines with their indices, and what normalising them changed
+    fn lines(
+        content: &str,
+        normalization: Normalization,
+    ) -> (Vec<(usize, String)>, NormalizationReport) {
+        let mut stream = stream(content, normalization);
+        let lines = stream.lines().collect::<Result<_>>().unwrap();
+        (lines, stream.report())
+    }
+
+    fn numbered(lines: &[&str]) -> Vec<(usize, String)> {
+        lines.iter().map(|l| l.to_string()).enumerate().collect()
+    }
+
+    #[test]
+    fn strips_a_bom_for_lines_and_the_reader() {
+        let (read, report) = lines("\u{feff}abc\n", Normalization::DEFAULT);
+        assert_eq!(read, numbered(&["abc"]));
+        assert!(report.bom);
+
+        let mut raw = String::new();
+        let mut stream = stream("\u{feff}abc\n", Normalization::DEFAULT);
+        stream.reader().read_line(&mut raw).unwrap();
+        assert_eq!(raw, "abc\n");
+    }
+
+    #[test]
+    fn strips_carriage_returns() {
+        let (read, report) = lines("a\r\nb\r\nc\n", Normalization::DEFAULT);
+        assert_eq!(read, numbered(&["a", "b", "c"]));
+        assert_eq!(report.cr_lines, 2);
+    }
+
+    #[test]
+    fn trims_trailing_whitespace_but_keeps_leading() {
+        let (read, report) = lines("    [D]    \n[N] [C] \t\n", Normalization::DEFAULT);
+        assert_eq!(read, numbered(&["    [D]", "[N] [C]"]));
+        assert_eq!(report.trimmed_lines, 2);
+    }
+
+    #[test]
+    fn drops_trailing_blank_lines_only() {
+        let (read, report) = lines("1\n\n\n2\n\n3\n\n\n", Normalization::DEFAULT);
+        // The blank lines held back between 2 and 3 keep their indices
+        assert_eq!(read, numbered(&["1", "", "", "2", "", "3"]));
+        assert_eq!(report.trailing_blank_lines, 2);
+        assert_eq!(report.to_string(), "dropped 2 blank lines at the end");
+    }
+
+    #[test]
+    fn blank_input_has_no_lines() {
+        let (read, report) = lines("\n\n", Normalization::DEFAULT);
+        assert!(read.is_empty());
+        assert_eq!(report.trailing_blank_lines, 2);
+    }
+
+    #[test]
+    fn none_keeps_the_input_as_it_is() {
+        let content = "\u{feff}a \r\n\n";
+        let (read, report) = lines(content, Normalization::NONE);
+        assert_eq!(read, numbered(&["\u{feff}a \r", ""]));
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn reports_every_change() {
+        let (_, report) = lines("\u{feff}a \r\n\n", Normalization::DEFAULT);
+        assert_eq!(
+            report.to_string(),
+            "removed a byte order mark, removed \\r from 1 lines, \
+             trimmed trailing whitespace on 1 lines, dropped 1 blank lines at the end"
+        );
+    }
+
+    #[test]
+    fn cleans_line_ends_for_the_reader() {
+        let mut input = stream("", Normalization::DEFAULT);
+        assert!(input.clean_line_end(0, false));
+        assert!(input.clean_line_end(1, true));
+        assert!(input.clean_line_end(3, true));
+        assert!(input.clean_line_end(2, false));
+        assert_eq!(
+            (input.report().cr_lines, input.report().trimmed_lines),
+            (2, 2)
+        );
+
+        let mut input = stream("", Normalization::NONE);
+        assert!(!input.clean_line_end(1, true));
+        assert!(!input.clean_line_end(1, false));
+        assert!(input.report().is_empty());
+    }
+
+    #[test]
+    fn collect_reads_what_is_left() {
+        let mut stream = stream("a\nb\nc\n", Normalization::DEFAULT);
+        stream.lines().next().unwrap().unwrap();
+        assert_eq!(stream.collect().unwrap().to_vec(), ["b", "c"]);
+    }
+}
@@ -0,0 +1,382 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::history::{append_history, load_history};
+use crate::{data_dirs, find_input_file, Config, Error, Input, Request, Result};
+
+const HEADER: &str = "# unix_time\tpart\toutcome\twait_s\tanswer";
+
+// What adventofcode.com said about a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    // Submitted too soon after the last one, the answer wasn't looked at
+    Wait,
+    // The part was already solved, or part 2 isn't unlocked yet
+    WrongLevel,
+}
+
+impl Outcome {
+    pub const VALUES: [Self; 6] = [
+        Self::Right,
+        Self::Wrong,
+        Self::TooHigh,
+        Self::TooLow,
+        Self::Wait,
+        Self::WrongLevel,
+    ];
+
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Self::Wrong | Self::TooHigh | Self::TooLow)
+    }
+
+    pub fn describe(self) -> &'static str {
+        match self {
+            Self::Right => "the right answer",
+            Self::Wrong => "wrong",
+            Self::TooHigh => "too high",
+            Self::TooLow => "too low",
+            Self::Wait => "submitted too soon after the last answer",
+            Self::WrongLevel => "for a part that's solved or locked",
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Right => "right",
+            Self::Wrong => "wrong",
+            Self::TooHigh => "too_high",
+            Self::TooLow => "too_low",
+            Self::Wait => "wait",
+            Self::WrongLevel => "wrong_level",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::VALUES
+            .into_iter()
+            .find(|o| o.to_string() == s)
+            .ok_or("Unknown outcome")
+    }
+}
+
+// An answer that was sent to adventofcode.com and what came back
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub unix_time: u64,
+    pub part: u8,
+    pub outcome: Outcome,
+    // How long the site asked to wait before the next answer
+    pub wait: Duration,
+    pub answer: String,
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}",
+            self.unix_time,
+            self.part,
+            self.outcome,
+            self.wait.as_secs(),
+            self.answer
+        )
+    }
+}
+
+impl Submission {
+    // The submission on line `i` of the file, in its Display format
+    fn new(file: &Input, i: usize) -> Result<Self> {
+        let context = "Submission::new";
+        let line = &file[i];
+        let fields: Vec<&str> = line.splitn(5, '\t').collect();
+        if fields.len() != 5 {
+            return Err(file.error(context, "Bad record", i, line, "5 tab separated fields"));
+        }
+        let number = |field: &str| {
+            field.parse::<u64>().map_err(|e| {
+                file.error(context, "Bad record", i, field, "a number")
+                    .with_source(e)
+            })
+        };
+
+        Ok(Self {
+            unix_time: number(fields[0])?,
+            part: match fields[1] {
+                "1" => 1,
+                "2" => 2,
+                part => return Err(file.error(context, "Bad part", i, part, "1 or 2")),
+            },
+            outcome: fields[2]
+                .parse()
+                .map_err(|e| file.error(context, e, i, fields[2], "an outcome"))?,
+            wait: Duration::from_secs(number(fields[3])?),
+            answer: fields[4].to_string(),
+        })
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+// `data/<year>/dayN.submissions.txt`, next to the input when there is one so
+// it ends up in the vault with it
+pub fn submissions_file(year: u32, day: u32, data_dir: Option<&Path>) -> PathBuf {
+    let file = format!("day{}.submissions.txt", day);
+    match find_input_file(&format!("day{}.input.txt", day), year, data_dir) {
+        Ok(input) => input.with_file_name(file),
+        Err(_) => data_dirs(year, data_dir).remove(0).join(file),
+    }
+}
+
+// Every answer submitted for a day, oldest first
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Submissions {
+    pub path: PathBuf,
+    pub records: Vec<Submission>,
+}
+
+impl Submissions {
+    // Nothing submitted yet when there's no file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let records = load_history(&path, Submission::new)?;
+        Ok(Self { path, records })
+    }
+
+    pub fn record(&mut self, submission: Submission) -> Result<()> {
+        append_history(&self.path, HEADER, std::slice::from_ref(&submission))?;
+        self.records.push(submission);
+        Ok(())
+    }
+
+    // Why `answer` shouldn't be sent for `part` at `unix_time`: the part is
+    // solved, the answer is known to be wrong or out of the known bounds, or
+    // the site asked to wait. Answers are written as `show` gives them
+    pub fn refusal(
+        &self,
+        part: u8,
+        answer: &str,
+        unix_time: u64,
+        show: &dyn Fn(&str) -> String,
+    ) -> Option<String> {
+        let part_records = || self.records.iter().filter(|r| r.part == part);
+        if let Some(right) = part_records().find(|r| r.outcome == Outcome::Right) {
+            return Some(format!(
+                "Part {} was already solved with {}",
+                part,
+                show(&right.answer)
+            ));
+        }
+        if let Some(known) = part_records().find(|r| r.answer == answer && r.outcome.is_wrong()) {
+            return Some(format!(
+                "{} was already submitted for part {} and was {}",
+                show(answer),
+                part,
+                known.outcome.describe()
+            ));
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |outcome, out_of_bounds: fn(i128, i128) -> bool| {
+                part_records()
+                    .filter(|r| r.outcome == outcome)
+                    .find(|r| r.answer.parse().is_ok_and(|b| out_of_bounds(value, b)))
+            };
+            if let Some(high) = bound(Outcome::TooHigh, |value, high| value >= high) {
+                return Some(format!(
+                    "{} is too high, {} already was",
+                    show(answer),
+                    show(&high.answer)
+                ));
+            }
+            if let Some(low) = bound(Outcome::TooLow, |value, low| value <= low) {
+                return Some(format!(
+                    "{} is too low, {} already was",
+                    show(answer),
+                    show(&low.answer)
+                ));
+            }
+        }
+
+        let wait_until = self
+            .records
+            .iter()
+            .map(|r| r.unix_time + r.wait.as_secs())
+            .max()
+            .unwrap_or_default();
+        if wait_until > unix_time {
+            return Some(format!(
+                "The site asked to wait before the next answer, {}s left",
+                wait_until - unix_time
+            ));
+        }
+        None
+    }
+}
+
+// The text of the page's <article>, where the verdict is
+fn article_text(body: &str) -> String {
+    let article = match (body.find("<article"), body.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &body[start..end],
+        _ => body,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// Reads "You have 4m 53s left to wait" and "Please wait one minute before
+// trying again"
+fn parse_wait(text: &str) -> Duration {
+    if let Some((before, _)) = text.split_once(" left to wait") {
+        let left = before.rsplit("You have ").next().unwrap_or_default();
+        let seconds = left.split_whitespace().map(|part| {
+            let (number, unit) = part.split_at(part.len().saturating_sub(1));
+            let number: u64 = number.parse().unwrap_or_default();
+            match unit {
+                "h" => number * 3600,
+                "m" => number * 60,
+                _ => number,
+            }
+        });
+        return Duration::from_secs(seconds.sum());
+    }
+
+    let words: Vec<&str> = text.split_whitespace().collect();
+    for window in words.windows(3) {
+        let [wait, number, unit] = window else {
+            continue;
+        };
+        if !wait.eq_ignore_ascii_case("wait") {
+            continue;
+        }
+        let number = match *number {
+            "one" | "a" | "an" => 1,
+            "two" => 2,
+            "three" => 3,
+            "four" => 4,
+            "five" => 5,
+            "ten" => 10,
+            n => match n.parse::<u64>() {
+                Ok(n) => n,
+                Err(_) => continue,
+            },
+        };
+        if unit.starts_with("minute") {
+            return Duration::from_secs(number * 60);
+        }
+        if unit.starts_with("second") {
+            return Duration::from_secs(number);
+        }
+    }
+    Duration::ZERO
+}
+
+// What the answer page says, None when it's none of the known verdicts
+pub fn parse_response(body: &str) -> Option<(Outcome, Duration)> {
+    let text = article_text(body);
+    let outcome = if text.contains("That's the right answer") {
+        Outcome::Right
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Outcome::TooHigh
+        } else if text.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if text.contains("answer too recently") {
+        Outcome::Wait
+    } else if text.contains("the right level") {
+        Outcome::WrongLevel
+    } else {
+        return None;
+    };
+    Some((outcome, parse_wait(&text)))
+}
+
+fn urlencode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+// Posts `answer` for a part and records what the site said. Answers the
+// history already rules out are refused without asking the site, saying why
+// with answers as `show` gives them, like hashed when spoiler-safe
+pub fn submit_answer(
+    config: &Config,
+    submissions: &mut Submissions,
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: &str,
+    show: &dyn Fn(&str) -> String,
+) -> Result<Submission> {
+    if let Some(refusal) = submissions.refusal(part, answer, now(), show) {
+        return Err(Error::Refused { message: refusal });
+    }
+
+    let url = format!("{}/{}/day/{}/answer", config.base_url(), year, day);
+    let form = format!("level={}&answer={}", part, urlencode(answer));
+    let response = Request::post_form(&url, form)
+        .header("Cookie", format!("session={}", config.session()?))
+        .header("User-Agent", config.user_agent())
+        .send()?;
+    let Some((outcome, wait)) = parse_response(&response.body).filter(|_| response.is_success())
+    else {
+        return Err(Error::Http {
+            url,
+            message: format!(
+                "got {} without a verdict: {}",
+                response.status,
+                article_text(&response.body)
+                    .chars()
+                    .take(200)
+                    .collect::<String>()
+            ),
+        });
+    };
+
+    let submission = Submission {
+        unix_time: now(),
+        part,
+        outcome,
+        wait,
+        answer: answer.to_string(),
+    };
+    submissions.record(submission.clone())?;
+    Ok(submission)
+}
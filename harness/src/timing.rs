@@ -0,0 +1,262 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Read,
+    Parse,
+    Part1,
+    Part2,
+    Total,
+}
+
+impl Stage {
+    pub const VALUES: [Self; 5] = [
+        Self::Read,
+        Self::Parse,
+        Self::Part1,
+        Self::Part2,
+        Self::Total,
+    ];
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Read => "read",
+            Self::Parse => "parse",
+            Self::Part1 => "part 1",
+            Self::Part2 => "part 2",
+            Self::Total => "total",
+        };
+        // Padding so stages line up in tables
+        f.pad(name)
+    }
+}
+
+// Reads the names Display writes
+impl FromStr for Stage {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Stage::VALUES
+            .into_iter()
+            .find(|stage| stage.to_string() == s)
+            .ok_or("Stage::from_str::Expected read, parse, part 1, part 2 or total")
+    }
+}
+
+// Wall-clock time of each stage of a single run on the real input
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub read: Duration,
+    pub parse: Duration,
+    pub part_1: Duration,
+    pub part_2: Duration,
+}
+
+impl Timings {
+    pub fn get(&self, stage: Stage) -> Duration {
+        match stage {
+            Stage::Read => self.read,
+            Stage::Parse => self.parse,
+            Stage::Part1 => self.part_1,
+            Stage::Part2 => self.part_2,
+            Stage::Total => self.read + self.parse + self.part_1 + self.part_2,
+        }
+    }
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Timings:")?;
+        for (i, stage) in Stage::VALUES.iter().enumerate() {
+            let separator = if i == 0 { "" } else { "," };
+            write!(f, "{} {} {}", separator, stage, Time(self.get(*stage)))?;
+        }
+        Ok(())
+    }
+}
+
+// Runs `f` and returns its result together with how long it took
+pub fn time<R>(f: impl FnOnce() -> R) -> (R, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+// Shows a duration with a unit that fits it, e.g. `12.34ms`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Time(pub Duration);
+
+impl Display for Time {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let nanos = self.0.as_nanos() as f64;
+        let time = if nanos < 1e3 {
+            format!("{}ns", nanos)
+        } else if nanos < 1e6 {
+            format!("{:.2}µs", nanos / 1e3)
+        } else if nanos < 1e9 {
+            format!("{:.2}ms", nanos / 1e6)
+        } else {
+            format!("{:.2}s", nanos / 1e9)
+        };
+        f.pad(&time)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(durations: &[Duration]) -> Self {
+        if durations.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted = durations.to_vec();
+        sorted.sort();
+        let len = sorted.len();
+        let median = if len.is_multiple_of(2) {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+
+        let secs = sorted.iter().map(|d| d.as_secs_f64()).collect::<Vec<_>>();
+        let mean = secs.iter().sum::<f64>() / len as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / len as f64;
+
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+// Every run of a day in benchmark mode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bench {
+    pub day: u32,
+    pub runs: Vec<Timings>,
+}
+
+impl Bench {
+    pub fn stats(&self, stage: Stage) -> Stats {
+        Stats::new(&self.runs.iter().map(|t| t.get(stage)).collect::<Vec<_>>())
+    }
+}
+
+fn write_header(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+        f,
+        " {:>10} {:>10} {:>10} {:>10}",
+        "Min", "Median", "Mean", "Stddev"
+    )
+}
+
+fn write_stats(f: &mut std::fmt::Formatter<'_>, stats: Stats) -> std::fmt::Result {
+    write!(
+        f,
+        " {:>10} {:>10} {:>10} {:>10}",
+        Time(stats.min),
+        Time(stats.median),
+        Time(stats.mean),
+        Time(stats.stddev)
+    )
+}
+
+impl Display for Bench {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Day {} ({} runs)", self.day, self.runs.len())?;
+        write!(f, "  {:<6}", "Stage")?;
+        write_header(f)?;
+        for stage in Stage::VALUES {
+            write!(f, "\n  {:<6}", stage)?;
+            write_stats(f, self.stats(stage))?;
+        }
+        Ok(())
+    }
+}
+
+// Total time of several benchmarked days side by side
+pub struct BenchTable<'a>(pub &'a [Bench]);
+
+impl Display for BenchTable<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "  {:<6} {:>4}", "Day", "Runs")?;
+        write_header(f)?;
+        for bench in self.0 {
+            write!(f, "\n  {:<6} {:>4}", bench.day, bench.runs.len())?;
+            write_stats(f, bench.stats(Stage::Total))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_millis(v)).collect()
+    }
+
+    #[test]
+    fn stats_of_an_odd_number_of_runs() {
+        let stats = Stats::new(&millis(&[9, 1, 5]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        // sqrt((16 + 16 + 0) / 3) ms, the population standard deviation
+        assert_eq!(stats.stddev.as_micros(), 3265);
+    }
+
+    #[test]
+    fn median_of_an_even_number_of_runs_is_halfway() {
+        let stats = Stats::new(&millis(&[4, 1, 2, 10]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(17) / 4);
+    }
+
+    #[test]
+    fn stats_of_identical_and_missing_runs() {
+        let stats = Stats::new(&millis(&[7]));
+        assert_eq!((stats.min, stats.median), (stats.mean, stats.mean));
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert_eq!(Stats::new(&[]), Stats::default());
+    }
+
+    #[test]
+    fn bench_totals_every_stage() {
+        let run = |ms| Timings {
+            read: Duration::from_millis(ms),
+            parse: Duration::from_millis(ms),
+            part_1: Duration::from_millis(ms),
+            part_2: Duration::from_millis(ms),
+        };
+        let bench = Bench {
+            day: 1,
+            runs: vec![run(1), run(3)],
+        };
+        assert_eq!(bench.stats(Stage::Parse).mean, Duration::from_millis(2));
+        assert_eq!(bench.stats(Stage::Total).min, Duration::from_millis(4));
+    }
+
+    #[test]
+    fn time_picks_a_unit() {
+        let shown = |nanos| Time(Duration::from_nanos(nanos)).to_string();
+        assert_eq!(shown(999), "999ns");
+        assert_eq!(shown(12_345), "12.35µs");
+        assert_eq!(shown(12_345_678), "12.35ms");
+        assert_eq!(shown(2_500_000_000), "2.50s");
+    }
+}
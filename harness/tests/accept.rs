@@ -0,0 +1,152 @@
+use std::path::PathBuf;
+
+use aoc_harness::{
+    answer_hash, Answer, AoC, Error, FnSolution, Input, Options, Result, Unsolved, ANSWER_KEY_FILE,
+};
+
+// A data directory with day 1 of 2022 and no examples, removed when dropped
+// so a failing test doesn't leave it behind
+struct DataDir(PathBuf);
+
+impl DataDir {
+    fn new(name: &str, input: &str) -> Self {
+        let dir = Self(std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id())));
+        std::fs::create_dir_all(dir.year()).unwrap();
+        dir.write("day1.input.txt", input);
+        dir.write("day1.fixtures.txt", "");
+        dir
+    }
+
+    fn year(&self) -> PathBuf {
+        self.0.join("2022")
+    }
+
+    fn write(&self, file: &str, content: &str) {
+        std::fs::write(self.year().join(file), content).unwrap();
+    }
+
+    fn options(&self) -> Options {
+        Options {
+            data_dir: Some(self.0.clone()),
+            ..Options::default()
+        }
+    }
+}
+
+impl Drop for DataDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+fn line_count(input: Input) -> Result<Answer<usize, Unsolved>> {
+    Ok(Answer(input.len(), Unsolved))
+}
+
+#[test]
+fn keeps_the_answers_of_unsolved_parts() {
+    let data_dir = DataDir::new("accept", "a\nb\nc\n");
+    data_dir.write(ANSWER_KEY_FILE, "key\n");
+    data_dir.write(
+        "day1.answers.txt",
+        "[day1.input.txt]\npart1 = 2\npart2 = 42\n\n[day1.other.txt]\npart1 = 7\n",
+    );
+
+    let aoc = AoC::<usize, Unsolved>::new(2022, 1).with_options(data_dir.options());
+    let path = aoc.accept(&FnSolution(line_count)).unwrap();
+    let answers = std::fs::read_to_string(&path).unwrap();
+
+    assert!(answers.ends_with(&format!(
+        "[day1.input.txt]\npart1 = {}\npart2 = 42\n\n[day1.other.txt]\npart1 = 7\n",
+        answer_hash(b"key", "3")
+    )));
+}
+
+#[test]
+fn checks_answers_against_their_hashes() {
+    let data_dir = DataDir::new("verify", "a\nb\nc\n");
+
+    let aoc = AoC::<usize, Unsolved>::new(2022, 1).with_options(data_dir.options());
+    let path = aoc.accept(&FnSolution(line_count)).unwrap();
+    let answers = std::fs::read_to_string(path).unwrap();
+    let key = std::fs::read_to_string(data_dir.year().join(ANSWER_KEY_FILE)).unwrap();
+    let (results, _) = aoc.run_captured(&FnSolution(line_count)).unwrap();
+    let hidden = AoC::<usize, Unsolved>::new(2022, 1).with_options(Options {
+        spoiler_safe: true,
+        ..data_dir.options()
+    });
+    let (hidden, _) = hidden.run_captured(&FnSolution(line_count)).unwrap();
+    data_dir.write("day1.input.txt", "a\nb\n");
+    let (changed, _) = aoc.run_captured(&FnSolution(line_count)).unwrap();
+
+    let hash = answer_hash(key.trim().as_bytes(), "3");
+    assert!(answers.contains(&format!("part1 = {}\n", hash)));
+    assert!(!answers.contains("part2"));
+    assert_eq!(results[0].answer, "3");
+    assert_eq!(results[0].accepted.as_deref(), Some(hash.as_str()));
+    assert!(!results[0].changed());
+    assert_eq!(hidden[0].answer, hash);
+    assert!(!hidden[0].changed());
+    assert_eq!(
+        (hidden[1].hash.as_deref(), hidden[1].answer.as_str()),
+        (None, "not implemented yet")
+    );
+    assert!(changed[0].changed());
+}
+
+#[test]
+fn only_hiding_and_verifying_need_the_answer_key() {
+    let data_dir = DataDir::new("keyless", "a\nb\nc\n");
+    data_dir.write(
+        "day1.answers.txt",
+        "[day1.input.txt]\npart1 = 0123456789abcdef\n",
+    );
+    let aoc = |options| AoC::<usize, Unsolved>::new(2022, 1).with_options(options);
+
+    let (results, _) = aoc(data_dir.options())
+        .run_captured(&FnSolution(line_count))
+        .unwrap();
+    assert_eq!(
+        (results[0].hash.as_ref(), results[0].accepted.as_ref()),
+        (None, None)
+    );
+    assert_eq!(results[0].answer, "3");
+
+    let verify = Options {
+        verify: true,
+        ..data_dir.options()
+    };
+    let spoiler_safe = Options {
+        spoiler_safe: true,
+        ..data_dir.options()
+    };
+    for options in [verify, spoiler_safe] {
+        match aoc(options).run_captured(&FnSolution(line_count)) {
+            Err(Error::Config { message }) => assert!(message.contains(ANSWER_KEY_FILE)),
+            other => panic!("Expected a missing key, got {:?}", other),
+        }
+    }
+    // A new key couldn't check what's already accepted
+    assert!(!data_dir.year().join(ANSWER_KEY_FILE).exists());
+}
+
+fn line_counts(input: Input) -> Result<Answer<usize, usize>> {
+    Ok(Answer(input.len(), input.len()))
+}
+
+#[test]
+fn names_each_wrong_part_once() {
+    let data_dir = DataDir::new("mismatch", "a\n");
+    data_dir.write("day1.a.txt", "a\n");
+    data_dir.write("day1.b.txt", "a\nb\n");
+    data_dir.write(
+        "day1.fixtures.txt",
+        "[day1.a.txt]\npart1 = 5\npart2 = 5\n\n[day1.b.txt]\npart1 = 5\npart2 = 5\n",
+    );
+
+    let aoc = AoC::<usize, usize>::new(2022, 1).with_options(data_dir.options());
+    match aoc.accept(&FnSolution(line_counts)) {
+        Err(Error::TestMismatch { parts, .. }) => assert_eq!(parts, [1, 2]),
+        other => panic!("Expected a mismatch, got {:?}", other),
+    }
+}
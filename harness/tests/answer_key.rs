@@ -0,0 +1,14 @@
+// On its own, the environment it sets is shared by every test of a binary
+use aoc_harness::{answer_key, ANSWER_KEY_VAR};
+
+#[test]
+fn the_key_can_come_from_the_environment() {
+    let data_dir = std::env::temp_dir().join(format!("aoc-key-{}", std::process::id()));
+    std::env::set_var(ANSWER_KEY_VAR, " shared \n");
+    let key = answer_key(2022, Some(&data_dir), true);
+    std::env::remove_var(ANSWER_KEY_VAR);
+
+    assert_eq!(key.unwrap(), b"shared");
+    // Nothing is made when the key is given
+    assert!(!data_dir.exists());
+}
@@ -0,0 +1,56 @@
+use aoc_harness::{answer_hash, hmac_sha256, sha256, to_hex};
+
+#[test]
+fn matches_sha256_test_vectors() {
+    assert_eq!(
+        to_hex(&sha256(b"")),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+    assert_eq!(
+        to_hex(&sha256(b"abc")),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    // Two blocks, the padding doesn't fit after the message
+    assert_eq!(
+        to_hex(&sha256(
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+        )),
+        "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+    );
+    assert_eq!(
+        to_hex(&sha256(&[b'a'; 1000])),
+        "41edece42d63e8d9bf515a9ba6932e1c20cbc9f5a5d134645adb5db1b9737ea3"
+    );
+}
+
+// RFC 4231 test cases 1, 2 and 6
+#[test]
+fn matches_hmac_sha256_test_vectors() {
+    assert_eq!(
+        to_hex(&hmac_sha256(&[0x0b; 20], b"Hi There")),
+        "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7"
+    );
+    assert_eq!(
+        to_hex(&hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+    // A key longer than a block is hashed first
+    assert_eq!(
+        to_hex(&hmac_sha256(
+            &[0xaa; 131],
+            b"Test Using Larger Than Block-Size Key - Hash Key First"
+        )),
+        "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+    );
+}
+
+#[test]
+fn hashes_answers_as_printed() {
+    let key = b"6b6579";
+    assert_eq!(
+        answer_hash(key, "7581"),
+        to_hex(&hmac_sha256(key, b"7581"))[..16]
+    );
+    assert_ne!(answer_hash(key, "7581"), answer_hash(b"other", "7581"));
+    assert_ne!(answer_hash(key, "#.\n.#"), answer_hash(key, "#.\n#."));
+}
@@ -0,0 +1,57 @@
+use aoc_harness::{unlock_time, Format, Leaderboard};
+
+// Bob wins day 1 and Alice overtakes him on day 2
+const EXPORT: &str = r#"{
+  "event": "2022",
+  "owner_id": 1,
+  "members": {
+    "1": {"id": 1, "name": "Alice", "completion_day_level": {
+      "1": {"1": {"get_star_ts": 1669871100}, "2": {"get_star_ts": 1669871400}},
+      "2": {"1": {"get_star_ts": 1669957260}, "2": {"get_star_ts": 1669957320}}
+    }},
+    "2": {"id": 2, "name": "Bob", "completion_day_level": {
+      "1": {"1": {"get_star_ts": 1669870860}, "2": {"get_star_ts": 1669870920}},
+      "2": {"1": {"get_star_ts": 1669960800}}
+    }},
+    "3": {"id": 3, "name": null, "completion_day_level": {}}
+  }
+}"#;
+
+#[test]
+fn ranks_members() {
+    assert_eq!(unlock_time(2022, 1), 1669870800);
+    let leaderboard = Leaderboard::parse(EXPORT).unwrap();
+    assert_eq!(leaderboard.year, 2022);
+    assert_eq!(leaderboard.last_day(), 2);
+
+    let standings: Vec<_> = leaderboard
+        .standings()
+        .iter()
+        .map(|s| {
+            (
+                leaderboard.members[s.member].name.as_str(),
+                s.rank,
+                s.rank_change,
+                s.score,
+            )
+        })
+        .collect();
+    assert_eq!(
+        standings,
+        [
+            ("Alice", 1, 1, 10),
+            ("Bob", 2, -1, 8),
+            ("(anonymous user #3)", 3, 0, 0)
+        ]
+    );
+
+    assert_eq!(
+        leaderboard.render(Format::Csv),
+        "year,rank,rank_change,id,name,score,stars,day,part1_s,part2_s,delta_s
+2022,1,1,1,Alice,10,4,1,300,600,300
+2022,1,1,1,Alice,10,4,2,60,120,60
+2022,2,-1,2,Bob,8,3,1,60,120,60
+2022,2,-1,2,Bob,8,3,2,3600,,
+2022,3,0,3,(anonymous user #3),0,0,,,,"
+    );
+}
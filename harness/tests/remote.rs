@@ -0,0 +1,170 @@
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::thread;
+
+use std::time::Duration;
+
+use aoc_harness::{
+    answer_hash, fetch_input, parse_response, submit_answer, Config, Error, Fetched, Outcome,
+    Submissions,
+};
+
+fn temp_dir(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()))
+}
+
+// Answers a single request with `body` and hands back the request it got
+fn stub_server(body: &'static str) -> (String, thread::JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = Vec::new();
+        let mut buffer = [0; 1024];
+        let head_end = loop {
+            let n = stream.read(&mut buffer).unwrap();
+            request.extend_from_slice(&buffer[..n]);
+            if let Some(i) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                break i + 4;
+            }
+        };
+        let length: usize = String::from_utf8_lossy(&request)
+            .lines()
+            .find_map(|l| l.strip_prefix("Content-Length: ")?.parse().ok())
+            .unwrap_or(0);
+        let mut request_body = vec![0; head_end + length - request.len()];
+        stream.read_exact(&mut request_body).unwrap();
+        request.extend_from_slice(&request_body);
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        )
+        .unwrap();
+        String::from_utf8(request).unwrap()
+    });
+    (url, handle)
+}
+
+#[test]
+fn downloads_once() {
+    let data_dir = temp_dir("fetch");
+    let (url, server) = stub_server("1000\n2000\n");
+    let config = Config {
+        session: Some("cafe".to_string()),
+        base_url: Some(url),
+        contact: Some("me@example.com".to_string()),
+        ..Config::default()
+    };
+
+    let path = data_dir.join("2022").join("day1.input.txt");
+    assert_eq!(
+        fetch_input(&config, 2022, 1, Some(&data_dir), false).unwrap(),
+        Fetched::Downloaded(path.clone())
+    );
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+    assert!(request.contains("\r\nCookie: session=cafe\r\n"));
+    assert!(request.contains(&format!(
+        "\r\nUser-Agent: aoc_harness/{} (me@example.com)\r\n",
+        env!("CARGO_PKG_VERSION")
+    )));
+
+    // The stub is gone, so this would fail if it asked again
+    assert_eq!(
+        fetch_input(&config, 2022, 1, Some(&data_dir), false).unwrap(),
+        Fetched::Cached(path)
+    );
+    std::fs::remove_dir_all(data_dir).unwrap();
+}
+
+#[test]
+fn downloads_over_an_empty_placeholder() {
+    let data_dir = temp_dir("placeholder");
+    let path = data_dir.join("2022").join("day13.input.txt");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, "").unwrap();
+    let (url, server) = stub_server("abc\n");
+    let config = Config {
+        session: Some("cafe".to_string()),
+        base_url: Some(url),
+        ..Config::default()
+    };
+
+    assert_eq!(
+        fetch_input(&config, 2022, 13, Some(&data_dir), false).unwrap(),
+        Fetched::Downloaded(path.clone())
+    );
+    server.join().unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "abc\n");
+    std::fs::remove_dir_all(data_dir).unwrap();
+}
+
+#[test]
+fn reads_verdicts() {
+    let verdict =
+        |text: &str| parse_response(&format!("<main><article><p>{}</p></article></main>", text));
+    assert_eq!(
+        verdict("That's the right answer!  You are <span>one gold star</span> closer."),
+        Some((Outcome::Right, Duration::ZERO))
+    );
+    assert_eq!(
+        verdict("That's not the right answer; your answer is too low.  Please wait one minute before trying again."),
+        Some((Outcome::TooLow, Duration::from_secs(60)))
+    );
+    assert_eq!(
+        verdict("That's not the right answer.  Please wait 5 minutes before trying again."),
+        Some((Outcome::Wrong, Duration::from_secs(300)))
+    );
+    assert_eq!(
+        verdict("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 53s left to wait."),
+        Some((Outcome::Wait, Duration::from_secs(293)))
+    );
+    assert_eq!(
+        verdict("You don't seem to be solving the right level.  Did you already complete it?"),
+        Some((Outcome::WrongLevel, Duration::ZERO))
+    );
+    assert_eq!(verdict("Please log in."), None);
+}
+
+#[test]
+fn refuses_known_wrong_answers() {
+    let dir = temp_dir("submit");
+    let mut submissions = Submissions::load(dir.join("day1.submissions.txt")).unwrap();
+    let (url, server) = stub_server(
+        "<article><p>That's not the right answer; your answer is too high.</p></article>",
+    );
+    let config = Config {
+        session: Some("cafe".to_string()),
+        base_url: Some(url),
+        ..Config::default()
+    };
+
+    let show = |answer: &str| answer.to_string();
+    let submission = submit_answer(&config, &mut submissions, 2022, 1, 1, "42", &show).unwrap();
+    assert_eq!(submission.outcome, Outcome::TooHigh);
+    let request = server.join().unwrap();
+    assert!(request.starts_with("POST /2022/day/1/answer HTTP/1.1\r\n"));
+    assert!(request.ends_with("\r\n\r\nlevel=1&answer=42"));
+
+    // The same answer again and a higher one never get to the stub, which is
+    // gone by now. Why is said without the answers when they're hidden
+    let hide = |answer: &str| answer_hash(b"key", answer);
+    for answer in ["42", "50"] {
+        match submit_answer(&config, &mut submissions, 2022, 1, 1, answer, &hide) {
+            Err(Error::Refused { message }) => {
+                assert!(message.contains(&hide(answer)), "{}", message);
+                assert!(!message.contains(answer), "{}", message);
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+    assert_eq!(
+        Submissions::load(&submissions.path).unwrap().records,
+        submissions.records
+    );
+    std::fs::remove_dir_all(dir).unwrap();
+}